@@ -0,0 +1,14 @@
+use clap::Parser;
+
+use ros_backend::cli::Command;
+
+#[derive(Parser, Debug)]
+#[command(name = "asn1-to-ros")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+fn main() {
+    Cli::parse().command.run();
+}
@@ -0,0 +1,131 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::conversion::RosTarget;
+use crate::package::BuildSystem;
+use crate::renames::Renames;
+
+/// File name of the configuration that is read from the working directory if no `--config` is
+/// given
+pub const DEFAULT_CONFIG: &str = "asn1-to-ros.toml";
+
+/// Generation settings read from a TOML file, e.g.
+///
+/// ```toml
+/// pdu = "cam"
+/// out = "generated"
+/// inputs = ["asn1/*.asn"]
+/// package = "ament"
+///
+/// [conversion]
+/// ros = "ros2"
+/// unit_helpers = true
+/// ```
+///
+/// Relative paths are resolved against the directory of the file. Command-line arguments take
+/// precedence over the file.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Main PDU name
+    pub pdu: Option<String>,
+    /// Output directory
+    pub out: Option<PathBuf>,
+    /// ASN.1 files, directories or glob patterns to compile, or `-` for stdin
+    #[serde(default)]
+    pub inputs: Vec<String>,
+    /// Globs of the files to compile from directories, see [crate::cli::SourceFilter]
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Globs of the files to skip in directories
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Build system of the generated ROS packages
+    pub package: Option<BuildSystem>,
+    /// Exit with an error if any type could not be generated
+    #[serde(default)]
+    pub deny_warnings: bool,
+    /// Options of the ROS messages
+    #[serde(default)]
+    pub msgs: MsgsConfig,
+    /// Options of the conversion headers
+    #[serde(default)]
+    pub conversion: ConversionConfig,
+    /// Renames of ROS messages and fields
+    #[serde(default)]
+    pub renames: Renames,
+}
+
+/// Options of the ROS messages, see [crate::cli::MsgsArgs]
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MsgsConfig {
+    #[serde(default)]
+    pub type_descriptions: bool,
+    #[serde(default)]
+    pub md5sums: bool,
+}
+
+/// Options of the conversion headers, see [crate::cli::ConversionArgs]
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConversionConfig {
+    #[serde(default)]
+    pub check_constraints: bool,
+    pub ros: Option<RosTarget>,
+    #[serde(default)]
+    pub type_adapters: bool,
+    #[serde(default)]
+    pub unit_helpers: bool,
+    #[serde(default)]
+    pub primitives: bool,
+}
+
+impl Config {
+    /// Parses a configuration, resolving its relative paths other than `-` against `base`
+    pub fn parse(content: &str, base: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut config: Config = toml::from_str(content)?;
+        config.out = config.out.map(|out| base.join(out));
+        config.inputs = config
+            .inputs
+            .iter()
+            .map(|input| match input.as_str() {
+                "-" => input.clone(),
+                _ => base.join(input).to_string_lossy().into_owned(),
+            })
+            .collect();
+        Ok(config)
+    }
+
+    /// Reads the configuration at `path`, or at [DEFAULT_CONFIG] if it exists
+    pub fn load(path: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(DEFAULT_CONFIG).is_file() => Path::new(DEFAULT_CONFIG),
+            None => return Ok(Config::default()),
+        };
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("reading {}: {e}", path.display()))?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        Config::parse(&content, base).map_err(|e| format!("parsing {}: {e}", path.display()).into())
+    }
+
+    /// Expands the input patterns into the ASN.1 files and directories to compile
+    pub fn input_paths(&self) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+        let mut paths = vec![];
+        for input in &self.inputs {
+            if input == "-" {
+                paths.push(PathBuf::from(input));
+                continue;
+            }
+            let matches = glob::glob(input)?.collect::<Result<Vec<_>, _>>()?;
+            if matches.is_empty() {
+                return Err(format!("no ASN.1 files match {input}").into());
+            }
+            paths.extend(matches);
+        }
+        Ok(paths)
+    }
+}
@@ -0,0 +1,646 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+use clap::{Args, Parser, Subcommand};
+use regex::Regex;
+
+use rasn_compiler::prelude::ir::ToplevelDefinition;
+use rasn_compiler::prelude::*;
+
+use crate::conversion::{Conversion, RosTarget};
+use crate::msgs::{dependency_order, Msgs};
+use crate::package::{conversion_package, msgs_package, primitives_package, BuildSystem};
+use crate::python_conversion::PythonConversion;
+use crate::rust_conversion::{RosCrate, RustConversion};
+use crate::validation::Validation;
+
+mod config;
+mod output;
+mod sources;
+pub use config::{Config, ConversionConfig, MsgsConfig, DEFAULT_CONFIG};
+pub use output::{diff_files, manifest_name, write_files, Files};
+pub use sources::{add_sources, Source, SourceFilter};
+
+/// Input arguments shared by all commands
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// Main PDU name
+    #[arg(short, long)]
+    pub pdu: Option<String>,
+    /// Output directory
+    #[arg(short, long)]
+    pub out: Option<PathBuf>,
+    /// Configuration file [default: asn1-to-ros.toml, if present]
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+    /// Exit with an error if any type could not be generated
+    #[arg(long)]
+    pub deny_warnings: bool,
+    /// Compare the generated files with the ones in the output directory instead of writing
+    /// them, printing a unified diff and exiting with an error if they differ
+    #[arg(long)]
+    pub check: bool,
+    /// ASN.1 files or directories to compile, or `-` for stdin, instead of the inputs of the
+    /// configuration
+    pub paths: Vec<PathBuf>,
+    #[command(flatten)]
+    pub filter: SourceFilter,
+}
+
+/// Input arguments merged with the configuration
+#[derive(Debug)]
+pub struct Input {
+    pub pdu: String,
+    pub out: PathBuf,
+    pub sources: Vec<Source>,
+    pub deny_warnings: bool,
+    pub check: bool,
+}
+
+impl InputArgs {
+    /// Merges the arguments into the configuration file, returning the input along with the
+    /// configuration for the remaining options
+    pub fn resolve(&self) -> (Input, Config) {
+        let config = or_exit(Config::load(self.config.as_deref()));
+        let missing = |flag: &str, key: &str| -> ! {
+            eprintln!("error: pass {flag} or set `{key}` in the configuration");
+            exit(2);
+        };
+        let input = Input {
+            pdu: self
+                .pdu
+                .clone()
+                .or(config.pdu.clone())
+                .unwrap_or_else(|| missing("--pdu", "pdu")),
+            out: self
+                .out
+                .clone()
+                .or(config.out.clone())
+                .unwrap_or_else(|| missing("--out", "out")),
+            sources: or_exit(self.sources(&config)),
+            deny_warnings: self.deny_warnings || config.deny_warnings,
+            check: self.check,
+        };
+        (input, config)
+    }
+
+    fn sources(&self, config: &Config) -> Result<Vec<Source>, Box<dyn Error>> {
+        let paths = match self.paths.is_empty() {
+            true => config.input_paths()?,
+            false => self.paths.clone(),
+        };
+        let filter = SourceFilter {
+            include: match self.filter.include.is_empty() {
+                true => config.include.clone(),
+                false => self.filter.include.clone(),
+            },
+            exclude: match self.filter.exclude.is_empty() {
+                true => config.exclude.clone(),
+                false => self.filter.exclude.clone(),
+            },
+        };
+        filter.sources(&paths)
+    }
+}
+
+impl Input {
+    fn compile(&self, backend: impl Backend) -> String {
+        self.compile_with(Compiler::new().with_backend(backend))
+    }
+
+    /// Compiles the sources with `compiler`, e.g. `Compiler::new()` for the rasn bindings
+    fn compile_with<B: Backend>(&self, compiler: Compiler<B, CompilerMissingParams>) -> String {
+        let compiler_res =
+            add_sources(compiler, &self.sources).and_then(|compiler| compiler.compile_to_string());
+        generated_or_exit(compiler_res, self.deny_warnings)
+    }
+
+    /// Writes the files generated by `generator` to the output directory, or compares them with
+    /// it in check mode
+    fn write(&self, generator: &str, files: &Files) {
+        if !self.check {
+            or_exit(write_files(&self.out, generator, files));
+            return;
+        }
+        let diffs = or_exit(diff_files(&self.out, generator, files));
+        if !diffs.is_empty() {
+            diffs.iter().for_each(|diff| print!("{diff}"));
+            eprintln!(
+                "error: {} file(s) in {} differ from the generated ones",
+                diffs.len(),
+                self.out.display()
+            );
+            exit(1);
+        }
+    }
+}
+
+/// Options of the conversion headers
+#[derive(Args, Clone, Debug)]
+pub struct ConversionArgs {
+    /// Reject out-of-range ROS values in toStruct
+    #[arg(long)]
+    pub check_constraints: bool,
+    /// ROS distributions to generate the headers for [default: both]
+    #[arg(long, value_enum)]
+    pub ros: Option<RosTarget>,
+    /// Emit rclcpp::TypeAdapter specializations (ROS 2)
+    #[arg(long)]
+    pub type_adapters: bool,
+    /// Emit getSI_*/setSI_* helpers for INTEGER types with a declared unit
+    #[arg(long)]
+    pub unit_helpers: bool,
+    /// Also write the etsi_its_primitives_conversion headers, or with `--package` the
+    /// etsi_its_primitives_conversion package, to `<OUT>/etsi_its_primitives_conversion`
+    #[arg(long)]
+    pub primitives: bool,
+}
+
+impl ConversionArgs {
+    /// Merges the arguments into the conversion options of the configuration
+    pub fn resolve(&self, config: &ConversionConfig) -> ConversionArgs {
+        ConversionArgs {
+            check_constraints: self.check_constraints || config.check_constraints,
+            ros: self.ros.or(config.ros),
+            type_adapters: self.type_adapters || config.type_adapters,
+            unit_helpers: self.unit_helpers || config.unit_helpers,
+            primitives: self.primitives || config.primitives,
+        }
+    }
+
+    pub fn backend(&self, pdu: &str) -> Conversion {
+        Conversion::default()
+            .set_main_pdu_name(pdu)
+            .set_check_constraints(self.check_constraints)
+            .set_ros_target(self.ros.unwrap_or_default())
+            .set_type_adapters(self.type_adapters)
+            .set_unit_helpers(self.unit_helpers)
+    }
+}
+
+/// Options of the ROS messages
+#[derive(Args, Clone, Debug)]
+pub struct MsgsArgs {
+    /// Also write the ROS 2 type description and RIHS01 hash of each message to
+    /// `type_description/<Name>.json`
+    #[arg(long)]
+    pub type_descriptions: bool,
+    /// Also write the ROS 1 MD5 sum of each message to `md5sums.txt`
+    #[arg(long)]
+    pub md5sums: bool,
+}
+
+impl MsgsArgs {
+    /// Merges the arguments into the message options of the configuration
+    pub fn resolve(&self, config: &MsgsConfig) -> MsgsArgs {
+        MsgsArgs {
+            type_descriptions: self.type_descriptions || config.type_descriptions,
+            md5sums: self.md5sums || config.md5sums,
+        }
+    }
+}
+
+/// Generates the ROS messages
+#[derive(Parser, Debug)]
+pub struct MsgsCommand {
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub msgs: MsgsArgs,
+    /// Generate a ROS package for the given build system, with the messages in `<OUT>/msg`
+    #[arg(long, value_enum)]
+    pub package: Option<BuildSystem>,
+}
+
+impl MsgsCommand {
+    pub fn run(&self) {
+        let (input, config) = self.input.resolve();
+        let msgs = self.msgs.resolve(&config.msgs);
+        let generated = input.compile(Msgs::default().set_renames(config.renames));
+        let files = msgs_files(
+            &generated,
+            &input.pdu,
+            self.package.or(config.package),
+            &msgs,
+        );
+        input.write("msgs", &files);
+    }
+}
+
+/// Generates the conversion headers
+#[derive(Parser, Debug)]
+pub struct ConversionCommand {
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub conversion: ConversionArgs,
+    /// Generate a ROS package for the given build system, with the headers in
+    /// `<OUT>/include/etsi_its_<PDU>_conversion`. Along with `--primitives`, the package goes to
+    /// `<OUT>/etsi_its_<PDU>_conversion`, next to the primitives package.
+    #[arg(long, value_enum)]
+    pub package: Option<BuildSystem>,
+}
+
+impl ConversionCommand {
+    pub fn run(&self) {
+        let (input, config) = self.input.resolve();
+        let package = self.package.or(config.package);
+        let conversion = self.conversion.resolve(&config.conversion);
+        let backend = conversion
+            .backend(&input.pdu)
+            .set_renames(config.renames.clone());
+        let generated = input.compile(backend.clone());
+        let mut files = conversion_files(&generated, &backend, &input.pdu, package);
+        if conversion.primitives {
+            // packages cannot be nested, so the packages are placed side by side
+            if package.is_some() {
+                let conversion_dir = PathBuf::from(format!("etsi_its_{}_conversion", input.pdu));
+                files = files
+                    .into_iter()
+                    .map(|(path, content)| (conversion_dir.join(path), content))
+                    .collect();
+            }
+            files.extend(primitives_files(&backend, package));
+        }
+        input.write("conversion", &files);
+    }
+}
+
+/// Generates the ROS messages and the conversion headers from a single compilation, into
+/// `<OUT>/etsi_its_<PDU>_msgs` and `<OUT>/etsi_its_<PDU>_conversion`
+#[derive(Parser, Debug)]
+pub struct AllCommand {
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub msgs: MsgsArgs,
+    #[command(flatten)]
+    pub conversion: ConversionArgs,
+    /// Generate ROS packages for the given build system
+    #[arg(long, value_enum)]
+    pub package: Option<BuildSystem>,
+}
+
+impl AllCommand {
+    pub fn run(&self) {
+        let (input, config) = self.input.resolve();
+        let msgs_args = self.msgs.resolve(&config.msgs);
+        let package = self.package.or(config.package);
+        let conversion = self.conversion.resolve(&config.conversion);
+        let pdu = &input.pdu;
+        let backend = conversion.backend(pdu).set_renames(config.renames.clone());
+        let msgs = Msgs::default().set_renames(config.renames);
+        let generated = input.compile(MsgsAndConversion(msgs, backend.clone()));
+        let msgs_dir = PathBuf::from(format!("etsi_its_{pdu}_msgs"));
+        let conversion_dir = PathBuf::from(format!("etsi_its_{pdu}_conversion"));
+        let mut files: Files = msgs_files(&section(&generated, "msgs"), pdu, package, &msgs_args)
+            .into_iter()
+            .map(|(path, content)| (msgs_dir.join(path), content))
+            .chain(
+                conversion_files(&section(&generated, "conversion"), &backend, pdu, package)
+                    .into_iter()
+                    .map(|(path, content)| (conversion_dir.join(path), content)),
+            )
+            .collect();
+        if conversion.primitives {
+            files.extend(primitives_files(&backend, package));
+        }
+        input.write("all", &files);
+    }
+}
+
+/// Generates the conversions between the rasn Rust types and the ROS messages
+#[derive(Parser, Debug)]
+pub struct RustConversionCommand {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// ROS 2 client library whose message structs are converted
+    #[arg(long, value_enum, default_value_t = RosCrate::R2r)]
+    pub ros_crate: RosCrate,
+}
+
+impl RustConversionCommand {
+    pub fn run(&self) {
+        let (input, config) = self.input.resolve();
+        // Compile rasn bindings, which the conversions refer to as `super::<module>`
+        let bindings = input.compile_with(Compiler::new());
+        let conversions = input.compile(
+            RustConversion::default()
+                .set_main_pdu_name(&input.pdu)
+                .set_ros_crate(self.ros_crate)
+                .set_renames(config.renames),
+        );
+        let files = Files::from([(
+            PathBuf::from(format!("etsi_its_{}_conversion.rs", input.pdu)),
+            format!("{bindings}\n\n{conversions}\n"),
+        )]);
+        input.write("rust-conversion", &files);
+    }
+}
+
+/// Generates the conversions between the asn1tools Python values and the rclpy messages
+#[derive(Parser, Debug)]
+pub struct PythonConversionCommand {
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+impl PythonConversionCommand {
+    pub fn run(&self) {
+        let (input, config) = self.input.resolve();
+        let backend = PythonConversion::default()
+            .set_main_pdu_name(&input.pdu)
+            .set_renames(config.renames);
+        let classes = input.compile(backend.clone());
+        let files = Files::from([(
+            PathBuf::from(format!("etsi_its_{}_conversion.py", input.pdu)),
+            format!("{}{classes}", backend.generate_header()),
+        )]);
+        input.write("python-conversion", &files);
+    }
+}
+
+/// Generates the validation headers of the ROS messages
+#[derive(Parser, Debug)]
+pub struct ValidationCommand {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// ROS distributions to generate the headers for [default: both]
+    #[arg(long, value_enum)]
+    pub ros: Option<RosTarget>,
+}
+
+impl ValidationCommand {
+    pub fn run(&self) {
+        let (input, config) = self.input.resolve();
+        let generated = input.compile(
+            Validation::default()
+                .set_main_pdu_name(&input.pdu)
+                .set_ros_target(self.ros.or(config.conversion.ros).unwrap_or_default())
+                .set_renames(config.renames),
+        );
+        input.write("validation", &validation_files(&generated));
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Generate the ROS messages
+    Msgs(MsgsCommand),
+    /// Generate the conversion headers
+    Conversion(ConversionCommand),
+    /// Generate the ROS messages and the conversion headers into
+    /// `<OUT>/etsi_its_<PDU>_msgs` and `<OUT>/etsi_its_<PDU>_conversion`
+    All(AllCommand),
+    /// Generate the conversions between the rasn Rust types and the ROS messages
+    RustConversion(RustConversionCommand),
+    /// Generate the conversions between the asn1tools Python values and the rclpy messages
+    PythonConversion(PythonConversionCommand),
+    /// Generate the validation headers of the ROS messages
+    Validation(ValidationCommand),
+}
+
+impl Command {
+    pub fn run(&self) {
+        match self {
+            Command::Msgs(command) => command.run(),
+            Command::Conversion(command) => command.run(),
+            Command::All(command) => command.run(),
+            Command::RustConversion(command) => command.run(),
+            Command::PythonConversion(command) => command.run(),
+            Command::Validation(command) => command.run(),
+        }
+    }
+}
+
+/// Backend generating both the ROS messages and the conversion headers of each module, in
+/// `<msgs>` and `<conversion>` sections
+struct MsgsAndConversion(Msgs, Conversion);
+
+impl Backend for MsgsAndConversion {
+    fn generate_module(
+        &self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        let msgs = self.0.generate_module(tlds.clone())?;
+        let conversion = self.1.generate_module(tlds)?;
+        // Both backends report the same unsupported types
+        let mut warnings = msgs.warnings;
+        let reported: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+        warnings.extend(
+            conversion
+                .warnings
+                .into_iter()
+                .filter(|w| !reported.contains(&w.to_string())),
+        );
+        Ok(GeneratedModule {
+            generated: Some(format!(
+                "<msgs>\n{}\n</msgs>\n<conversion>\n{}\n</conversion>",
+                msgs.generated.unwrap_or_default(),
+                conversion.generated.unwrap_or_default()
+            )),
+            warnings,
+        })
+    }
+}
+
+/// Joins the sections with the given tag of all modules
+fn section(generated: &str, tag: &str) -> String {
+    let re_section = Regex::new(&format!(r"<{tag}>\n((.|\n)*?)\n</{tag}>")).unwrap();
+    re_section
+        .captures_iter(generated)
+        .map(|caps| caps.get(1).unwrap().as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Splits the generated ROS messages into `.msg` files, along with their manifest, the
+/// packaging files and the requested message metadata
+pub fn msgs_files(
+    generated: &str,
+    pdu: &str,
+    package: Option<BuildSystem>,
+    args: &MsgsArgs,
+) -> Files {
+    let msg_dir = match package {
+        Some(_) => PathBuf::from("msg"),
+        None => PathBuf::new(),
+    };
+    let mut files = Files::new();
+
+    // Split generated code into individual messages
+    let re_name = Regex::new(r"##\s([\w-]+)\s(\w+)\b").unwrap();
+    let re_def = Regex::new(r"<typedef>\n((.|\n)*?)\n</typedef>").unwrap();
+    let mut messages = vec![];
+    generated.split_inclusive("</typedef>").for_each(|s| {
+        if let Some(def_caps) = re_def.captures(s) {
+            let definition = def_caps.get(1).unwrap().as_str();
+            let name = if let Some(name_caps) = re_name.captures(definition) {
+                name_caps.get(2).unwrap().as_str()
+            } else {
+                "unknown"
+            };
+            let path = msg_dir.join(format!("{}.msg", name));
+            files.insert(path, definition.to_string());
+            messages.push((name.to_string(), definition.to_string()));
+        }
+    });
+
+    // Manifest of message files in dependency order
+    let (file_name, manifest) = Msgs::default().generate_manifest(&messages, package);
+    files.insert(msg_dir.join(file_name), manifest);
+
+    // ROS 2 type descriptions and hashes
+    let package_name = format!("etsi_its_{pdu}_msgs");
+    if args.type_descriptions {
+        let descriptions = Msgs::default().generate_type_descriptions(&package_name, &messages);
+        for (file_name, description) in or_exit(descriptions) {
+            files.insert(Path::new("type_description").join(file_name), description);
+        }
+    }
+
+    // ROS 1 MD5 sums
+    if args.md5sums {
+        let (file_name, md5sums) =
+            or_exit(Msgs::default().generate_md5sums(&package_name, &messages));
+        files.insert(PathBuf::from(file_name), md5sums);
+    }
+
+    // Packaging files
+    if let Some(build) = package {
+        files.extend(
+            msgs_package(build, pdu, &dependency_order(&messages))
+                .into_iter()
+                .map(|(file_name, content)| (PathBuf::from(file_name), content)),
+        );
+    }
+    files
+}
+
+/// Splits the generated conversion headers into `convert*.h` files, along with the umbrella
+/// header and the packaging files
+pub fn conversion_files(
+    generated: &str,
+    backend: &Conversion,
+    pdu: &str,
+    package: Option<BuildSystem>,
+) -> Files {
+    let header_dir = match package {
+        Some(_) => Path::new("include").join(format!("etsi_its_{}_conversion", pdu)),
+        None => PathBuf::new(),
+    };
+    let mut files = Files::new();
+
+    // Split generated code into individual messages
+    let re_name = Regex::new(r"\/\/\/\/\s([\w-]+)\s(\w+)\b").unwrap();
+    let re_def = Regex::new(r"#<typedef>\n((.|\n)*?)#</typedef>").unwrap();
+    let mut type_names = vec![];
+    generated.split_inclusive("</typedef>").for_each(|s| {
+        if let Some(def_caps) = re_def.captures(s) {
+            let definition = def_caps.get(1).unwrap().as_str();
+            let name = if let Some(name_caps) = re_name.captures(definition) {
+                name_caps.get(2).unwrap().as_str()
+            } else {
+                "unknown"
+            };
+            let path = header_dir.join(format!("convert{}.h", name));
+            files.insert(path, definition.to_string());
+            type_names.push(name.to_string());
+        }
+    });
+
+    // Umbrella header including all conversion headers
+    if let Some((file_name, header)) = backend.generate_umbrella(&type_names) {
+        files.insert(header_dir.join(file_name), header);
+    }
+
+    // Packaging files
+    if let Some(build) = package {
+        files.extend(
+            conversion_package(build, pdu)
+                .into_iter()
+                .map(|(file_name, content)| (PathBuf::from(file_name), content)),
+        );
+    }
+    files
+}
+
+/// Splits the generated validation headers into `validate*.h` files
+pub fn validation_files(generated: &str) -> Files {
+    let re_name = Regex::new(r"\/\/\/\/\s([\w-]+)\s(\w+)\b").unwrap();
+    let re_def = Regex::new(r"#<typedef>\n((.|\n)*?)#</typedef>").unwrap();
+    let mut files = Files::new();
+    generated.split_inclusive("</typedef>").for_each(|s| {
+        if let Some(def_caps) = re_def.captures(s) {
+            let definition = def_caps.get(1).unwrap().as_str();
+            let name = if let Some(name_caps) = re_name.captures(definition) {
+                name_caps.get(2).unwrap().as_str()
+            } else {
+                "unknown"
+            };
+            files.insert(
+                PathBuf::from(format!("validate{}.h", name)),
+                definition.to_string(),
+            );
+        }
+    });
+    files
+}
+
+/// The `etsi_its_primitives_conversion` headers, in a directory named after the package they
+/// belong to, so that adding the output directory to the include path resolves their includes.
+/// With a `package`, the directory is the root of the primitives package instead.
+pub fn primitives_files(backend: &Conversion, package: Option<BuildSystem>) -> Files {
+    let package_dir = Path::new("etsi_its_primitives_conversion");
+    let header_dir = match package {
+        Some(_) => package_dir
+            .join("include")
+            .join("etsi_its_primitives_conversion"),
+        None => package_dir.to_path_buf(),
+    };
+    let mut files: Files = backend
+        .generate_primitives()
+        .into_iter()
+        .map(|(file_name, header)| (header_dir.join(file_name), header))
+        .collect();
+    if let Some(build) = package {
+        files.extend(
+            primitives_package(build)
+                .into_iter()
+                .map(|(file_name, content)| (package_dir.join(file_name), content)),
+        );
+    }
+    files
+}
+
+/// Returns the value of `result`, or prints its error and exits
+pub fn or_exit<T>(result: Result<T, Box<dyn Error>>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        exit(1);
+    })
+}
+
+/// Prints the warnings of a compilation, such as the types that could not be generated, to
+/// stderr and returns the generated code.
+///
+/// Exits with a non-zero code if the compilation failed, or if it emitted warnings while
+/// `deny_warnings` is set.
+pub fn generated_or_exit(
+    result: Result<CompileResult, Box<dyn Error>>,
+    deny_warnings: bool,
+) -> String {
+    let result = or_exit(result);
+    for warning in &result.warnings {
+        eprintln!("warning: {warning}");
+    }
+    if deny_warnings && !result.warnings.is_empty() {
+        eprintln!(
+            "error: {} warning(s) emitted with --deny-warnings",
+            result.warnings.len()
+        );
+        exit(1);
+    }
+    result.generated
+}
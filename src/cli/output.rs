@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use similar::TextDiff;
+
+/// Name of the file listing the files `generator` wrote into an output directory, so that its
+/// next run can remove the ones it no longer generates. Each generator has its own manifest, so
+/// that generators sharing an output directory never remove each other's files.
+pub fn manifest_name(generator: &str) -> String {
+    format!(".asn1-to-ros-{generator}-files")
+}
+
+/// Generated files, keyed by their path relative to the output directory
+pub type Files = BTreeMap<PathBuf, String>;
+
+/// Writes the files whose content changed into `out`, leaving the timestamps of the others
+/// untouched, and removes the files of the previous run of `generator` that are no longer
+/// generated
+pub fn write_files(out: &Path, generator: &str, files: &Files) -> Result<(), Box<dyn Error>> {
+    for (path, content) in files {
+        write_if_changed(&out.join(path), content)?;
+    }
+    for path in previous_files(out, generator)? {
+        if !files.contains_key(&path) {
+            remove_stale(out, &path)?;
+        }
+    }
+    write_if_changed(&out.join(manifest_name(generator)), &manifest(files))
+}
+
+/// Unified diffs between the files in `out` and the generated `files`, one per differing file,
+/// including the manifest and the files previously generated by `generator` that would be removed
+pub fn diff_files(
+    out: &Path,
+    generator: &str,
+    files: &Files,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut expected = files.clone();
+    expected.insert(PathBuf::from(manifest_name(generator)), manifest(files));
+    let mut diffs = vec![];
+    for (path, content) in &expected {
+        let existing = read_existing(&out.join(path))?;
+        if existing.as_deref() != Some(content) {
+            diffs.push(diff(path, existing.as_deref(), Some(content)));
+        }
+    }
+    for path in previous_files(out, generator)? {
+        if !expected.contains_key(&path) {
+            if let Some(existing) = read_existing(&out.join(&path))? {
+                diffs.push(diff(&path, Some(&existing), None));
+            }
+        }
+    }
+    Ok(diffs)
+}
+
+/// Files listed in the manifest of a previous run of `generator` into `out`, skipping any path
+/// outside of it
+pub fn previous_files(out: &Path, generator: &str) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let manifest = read_existing(&out.join(manifest_name(generator)))?.unwrap_or_default();
+    Ok(manifest
+        .lines()
+        .map(PathBuf::from)
+        .filter(|path| {
+            path.components().next().is_some()
+                && path.components().all(|c| matches!(c, Component::Normal(_)))
+        })
+        .collect())
+}
+
+fn manifest(files: &Files) -> String {
+    files
+        .keys()
+        .map(|path| format!("{}\n", path.display()))
+        .collect()
+}
+
+fn read_existing(path: &Path) -> Result<Option<String>, Box<dyn Error>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("reading {}: {e}", path.display()).into()),
+    }
+}
+
+/// Unified diff of a file, where `None` stands for a missing file
+fn diff(path: &Path, old: Option<&str>, new: Option<&str>) -> String {
+    let old_header = match old {
+        Some(_) => format!("a/{}", path.display()),
+        None => "/dev/null".into(),
+    };
+    let new_header = match new {
+        Some(_) => format!("b/{}", path.display()),
+        None => "/dev/null".into(),
+    };
+    TextDiff::from_lines(old.unwrap_or_default(), new.unwrap_or_default())
+        .unified_diff()
+        .header(&old_header, &new_header)
+        .to_string()
+}
+
+fn write_if_changed(path: &Path, content: &str) -> Result<(), Box<dyn Error>> {
+    if std::fs::read(path).is_ok_and(|existing| existing == content.as_bytes()) {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("creating {}: {e}", parent.display()))?;
+    }
+    std::fs::write(path, content).map_err(|e| format!("writing {}: {e}", path.display()).into())
+}
+
+fn remove_stale(out: &Path, path: &Path) -> Result<(), Box<dyn Error>> {
+    match std::fs::remove_file(out.join(path)) {
+        Err(e) if e.kind() != ErrorKind::NotFound => {
+            return Err(format!("removing {}: {e}", out.join(path).display()).into())
+        }
+        _ => {}
+    }
+    // remove the directories left empty, e.g. `msg` of a package that is no longer generated
+    for dir in path.ancestors().skip(1) {
+        if dir.as_os_str().is_empty() || std::fs::remove_dir(out.join(dir)).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
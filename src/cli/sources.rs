@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use glob::Pattern;
+use rasn_compiler::prelude::*;
+use walkdir::WalkDir;
+
+/// Files compiled from directory arguments if no `--include` is given
+const DEFAULT_INCLUDE: [&str; 2] = ["*.asn", "*.asn1"];
+
+/// Filter of the files compiled from directory arguments
+#[derive(Args, Clone, Debug, Default)]
+pub struct SourceFilter {
+    /// Compile only the files in directories whose path relative to the directory matches GLOB
+    /// [default: *.asn, *.asn1]
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+    /// Skip the files in directories whose path relative to the directory matches GLOB
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+}
+
+/// ASN.1 source to compile
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    Path(PathBuf),
+    Literal(String),
+}
+
+impl SourceFilter {
+    /// Expands the `paths` into the ASN.1 sources to compile. Directories are walked recursively
+    /// in file name order, and `-` reads a source from stdin. Sources given more than once are
+    /// compiled once, at their first position.
+    pub fn sources(&self, paths: &[PathBuf]) -> Result<Vec<Source>, Box<dyn Error>> {
+        let include = match self.include.is_empty() {
+            true => patterns(&DEFAULT_INCLUDE)?,
+            false => patterns(&self.include)?,
+        };
+        let exclude = patterns(&self.exclude)?;
+        let mut files = vec![];
+        for path in paths {
+            if path.is_dir() {
+                for entry in WalkDir::new(path).sort_by_file_name() {
+                    let entry = entry?;
+                    let relative = entry.path().strip_prefix(path)?;
+                    if entry.file_type().is_file()
+                        && include.iter().any(|pattern| pattern.matches_path(relative))
+                        && !exclude.iter().any(|pattern| pattern.matches_path(relative))
+                    {
+                        files.push(entry.into_path());
+                    }
+                }
+            } else {
+                files.push(path.clone());
+            }
+        }
+
+        let mut sources = vec![];
+        for (i, file) in files.iter().enumerate() {
+            if files[..i].contains(file) {
+                continue;
+            }
+            if file == Path::new("-") {
+                let mut literal = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut literal)
+                    .map_err(|e| format!("reading stdin: {e}"))?;
+                sources.push(Source::Literal(literal));
+            } else {
+                sources.push(Source::Path(file.clone()));
+            }
+        }
+        Ok(sources)
+    }
+}
+
+fn patterns(globs: &[impl AsRef<str>]) -> Result<Vec<Pattern>, Box<dyn Error>> {
+    globs
+        .iter()
+        .map(|glob| {
+            Pattern::new(glob.as_ref())
+                .map_err(|e| format!("invalid glob {}: {e}", glob.as_ref()).into())
+        })
+        .collect()
+}
+
+/// Adds the `sources` to `compiler`, failing if there are none
+pub fn add_sources<B: Backend>(
+    compiler: Compiler<B, CompilerMissingParams>,
+    sources: &[Source],
+) -> Result<Compiler<B, CompilerSourcesSet>, Box<dyn Error>> {
+    // Both compiler states have their own `add_asn_*` methods
+    macro_rules! add {
+        ($compiler:expr, $source:expr) => {
+            match $source {
+                Source::Path(path) => $compiler.add_asn_by_path(path),
+                Source::Literal(literal) => $compiler.add_asn_literal(literal.as_str()),
+            }
+        };
+    }
+    let (first, rest) = sources.split_first().ok_or("no ASN.1 files to compile")?;
+    let compiler = add!(compiler, first);
+    Ok(rest
+        .iter()
+        .fold(compiler, |compiler, source| add!(compiler, source)))
+}
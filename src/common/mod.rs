@@ -1,4 +1,13 @@
-use rasn_compiler::prelude::ir::IntegerType;
+use rasn_compiler::prelude::ir::{
+    per_visible_range_constraints, ASN1Type, Constraint, IntegerType,
+};
+use rasn_compiler::prelude::GeneratorError;
+
+use crate::conversion::RosTarget;
+
+mod units;
+
+pub use units::{unit_scale, UnitScale};
 
 pub trait IntegerTypeExt {
     fn to_str(self) -> &'static str;
@@ -58,3 +67,85 @@ pub fn to_ros_title_case(input: &str) -> String {
         input.to_string()
     }
 }
+
+/// Includes of the ROS message headers of the types `names`, followed by the `{pdu}_msgs`
+/// namespace alias, for the ROS distributions of `ros_target`
+pub fn ros_includes(pdu: &str, ros_target: RosTarget, names: &[&str]) -> String {
+    // gencpp generates `<package>/<Msg>.h` for ROS 1, only rosidl generates `.hpp` headers
+    let ros1_includes = names
+        .iter()
+        .map(|name| {
+            format!(
+                "#include <etsi_its_{pdu}_msgs/{}.h>\n",
+                to_ros_title_case(name)
+            )
+        })
+        .collect::<String>()
+        + &format!("namespace {pdu}_msgs = etsi_its_{pdu}_msgs;");
+    let ros2_includes = names
+        .iter()
+        .map(|name| {
+            format!(
+                "#include <etsi_its_{pdu}_msgs/msg/{}.hpp>\n",
+                to_ros_snake_case(name)
+            )
+        })
+        .collect::<String>()
+        + &format!("namespace {pdu}_msgs = etsi_its_{pdu}_msgs::msg;");
+    match ros_target {
+        RosTarget::Ros1 => ros1_includes,
+        RosTarget::Ros2 => ros2_includes,
+        RosTarget::Both => format!("#ifdef ROS1\n{ros1_includes}\n#else\n{ros2_includes}\n#endif"),
+    }
+}
+
+/// Value or size bounds of a type, as declared by the `VALUE_*`/`LENGTH_*` message constants
+#[derive(Clone, Debug)]
+pub struct RangeBounds {
+    pub min: Option<i128>,
+    pub max: Option<i128>,
+    pub is_size: bool,
+    pub is_extensible: bool,
+}
+
+impl RangeBounds {
+    pub fn prefix(&self) -> &'static str {
+        if self.is_size {
+            "LENGTH"
+        } else {
+            "VALUE"
+        }
+    }
+}
+
+pub fn range_bounds(
+    signed: bool,
+    constraints: &Vec<Constraint>,
+) -> Result<Option<RangeBounds>, GeneratorError> {
+    if constraints.is_empty() {
+        return Ok(None);
+    }
+    let per_constraints = per_visible_range_constraints(signed, constraints)?;
+    let bounds = RangeBounds {
+        min: per_constraints.min::<i128>(),
+        max: per_constraints.max::<i128>(),
+        is_size: per_constraints.is_size_constraint(),
+        is_extensible: per_constraints.is_extensible(),
+    };
+    // handle default size constraints
+    if bounds.is_size && !bounds.is_extensible && bounds.min == Some(0) && bounds.max.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(bounds))
+}
+
+/// Bounds of inline INTEGER, OCTET STRING and character string members, which the messages
+/// declare no constants for
+pub fn primitive_bounds(ty: &ASN1Type) -> Result<Option<RangeBounds>, GeneratorError> {
+    match ty {
+        ASN1Type::Integer(i) => range_bounds(true, &i.constraints),
+        ASN1Type::OctetString(o) => range_bounds(true, &o.constraints),
+        ASN1Type::CharacterString(c) => range_bounds(true, &c.constraints),
+        _ => Ok(None),
+    }
+}
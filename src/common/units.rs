@@ -0,0 +1,199 @@
+use std::sync::LazyLock;
+
+use rasn_compiler::prelude::ir::DistinguishedValue;
+use regex::Regex;
+
+/// `@unit: <text>` or `Unit: <text>` comment line
+static RE_UNIT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(?:@unit\s*:?|unit\s*:)\s*(.+?)\.?$").unwrap());
+/// Power of ten scale, e.g. `10^-7 degree` or `5 x 10^-3 m`
+static RE_POWER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:(\d+(?:[.,]\d+)?)\s*[x×*]\s*)?10\^(-?\d+)\s*(.*)$").unwrap());
+/// Decimal scale, e.g. `0,01 m/s`
+static RE_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d+(?:[.,]\d+)?)\s*(.*)$").unwrap());
+
+/// Scale and unit of an INTEGER type, such that `value * scale` is a quantity in `unit`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitScale {
+    pub scale: f64,
+    pub unit: String,
+}
+
+/// Extracts the unit and scale of an INTEGER type from the ETSI CDD conventions, i.e. either a
+/// `@unit: 0,01 m/s` or `Unit: 0.01 m/s` comment line, or a distinguished value such as
+/// `oneCentimeter(1)` or `oneMicrodegreeNorth(10)`.
+///
+/// Units with known prefixes are normalized to SI base units, apart from angles, which remain in
+/// degrees. Unknown units are passed on as written.
+pub fn unit_scale(
+    comments: &str,
+    distinguished_values: &Option<Vec<DistinguishedValue>>,
+) -> Option<UnitScale> {
+    comments.lines().find_map(comment_unit_scale).or_else(|| {
+        distinguished_values
+            .iter()
+            .flatten()
+            .find_map(distinguished_value_unit_scale)
+    })
+}
+
+fn comment_unit_scale(line: &str) -> Option<UnitScale> {
+    let line = line.trim().trim_start_matches('*').trim();
+    let text = RE_UNIT.captures(line)?.get(1)?.as_str();
+    let (scale, unit) = if let Some(caps) = RE_POWER.captures(text) {
+        let mantissa = caps
+            .get(1)
+            .map_or(Some(1.0), |m| parse_number(m.as_str()))?;
+        let exponent = caps[2].parse::<i32>().ok()?;
+        (mantissa * 10f64.powi(exponent), caps[3].to_string())
+    } else if let Some(caps) = RE_NUMBER.captures(text) {
+        (parse_number(&caps[1])?, caps[2].to_string())
+    } else {
+        (1.0, text.to_string())
+    };
+    let (unit, factor) = normalize_unit(unit.trim());
+    (!unit.is_empty()).then(|| UnitScale {
+        scale: round_significant(scale * factor),
+        unit,
+    })
+}
+
+fn distinguished_value_unit_scale(dvalue: &DistinguishedValue) -> Option<UnitScale> {
+    if dvalue.value <= 0 {
+        return None;
+    }
+    let words = split_camel_case(&dvalue.name);
+    let (multiplier, words) = parse_number_words(&words)?;
+    let mut unit = String::new();
+    let mut words = words.iter().peekable();
+    let numerator = words.next()?;
+    let numerator = match numerator.as_str() {
+        // milli and micro may be split off as separate words, e.g. in `oneMilliSec`
+        prefix @ ("milli" | "micro" | "centi") => format!("{prefix}{}", words.next()?),
+        unit => unit.to_string(),
+    };
+    unit_factor(&numerator)?;
+    unit.push_str(&numerator);
+    if words.next_if(|w| w.as_str() == "per").is_some() {
+        unit.push('/');
+        unit.push_str(words.next()?);
+        if words.next_if(|w| w.as_str() == "squared").is_some() {
+            unit.push_str("^2");
+        }
+    }
+    let (unit, factor) = normalize_unit(&unit);
+    Some(UnitScale {
+        scale: round_significant(multiplier * factor / dvalue.value as f64),
+        unit,
+    })
+}
+
+fn parse_number(number: &str) -> Option<f64> {
+    number.replace(',', ".").parse().ok()
+}
+
+/// Rounds off the representation errors of decimal scales, e.g. 1e-6 / 10
+fn round_significant(value: f64) -> f64 {
+    format!("{value:.12e}").parse().unwrap_or(value)
+}
+
+fn split_camel_case(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    for c in name.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_uppercase() && c != '-' => word.push(c),
+            _ if c == '-' => words.push(String::new()),
+            _ => words.push(c.to_ascii_lowercase().to_string()),
+        }
+    }
+    words.retain(|w| !w.is_empty());
+    words
+}
+
+/// Parses a leading number such as `one`, `ten`, `oneHundred` or `pointZeroOne`
+fn parse_number_words(words: &[String]) -> Option<(f64, &[String])> {
+    let digit = |word: &str| {
+        [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ]
+        .iter()
+        .position(|d| *d == word)
+    };
+    if words.first()? == "point" {
+        let digits = words[1..]
+            .iter()
+            .map_while(|w| digit(w))
+            .map(|d| d.to_string())
+            .collect::<String>();
+        let number = format!("0.{digits}").parse().ok()?;
+        return Some((number, &words[1 + digits.len()..]));
+    }
+    let mut number = match words.first()?.as_str() {
+        "ten" => 10.0,
+        "hundred" => 100.0,
+        "thousand" => 1000.0,
+        word => digit(word)? as f64,
+    };
+    let mut rest = &words[1..];
+    while let Some(word) = rest.first() {
+        match word.as_str() {
+            "hundred" => number *= 100.0,
+            "thousand" => number *= 1000.0,
+            _ => break,
+        }
+        rest = &rest[1..];
+    }
+    Some((number, rest))
+}
+
+/// SI base unit and factor of a single unit token
+fn unit_factor(unit: &str) -> Option<(&'static str, f64)> {
+    Some(match unit {
+        "m" | "meter" | "meters" | "metre" | "metres" => ("m", 1.0),
+        "cm" | "centimeter" | "centimeters" | "centimetre" | "centimetres" => ("m", 0.01),
+        "mm" | "millimeter" | "millimeters" | "millimetre" | "millimetres" => ("m", 0.001),
+        "km" | "kilometer" | "kilometers" | "kilometre" | "kilometres" => ("m", 1000.0),
+        "s" | "sec" | "secs" | "second" | "seconds" => ("s", 1.0),
+        "ms" | "millisec" | "millisecond" | "milliseconds" => ("s", 0.001),
+        "min" | "minute" | "minutes" => ("s", 60.0),
+        "h" | "hour" | "hours" => ("s", 3600.0),
+        "deg" | "degree" | "degrees" | "°" => ("degree", 1.0),
+        "microdegree" | "microdegrees" => ("degree", 1e-6),
+        "rad" | "radian" | "radians" => ("rad", 1.0),
+        "%" | "percent" => ("%", 1.0),
+        _ => return None,
+    })
+}
+
+/// Normalizes units such as `cm/s` or `0,1 m/s²` to SI base units, returning the normalized
+/// unit and the factor to apply
+fn normalize_unit(unit: &str) -> (String, f64) {
+    let token = |token: &str| -> (String, f64) {
+        let token = token.trim();
+        let (base, exponent) = match token.find(['^', '²', '³']) {
+            Some(i) => match &token[i..] {
+                "²" => (&token[..i], 2),
+                "³" => (&token[..i], 3),
+                power => match power[1..].parse::<i32>() {
+                    Ok(exponent) => (&token[..i], exponent),
+                    Err(_) => (token, 1),
+                },
+            },
+            None => (token, 1),
+        };
+        match unit_factor(&base.to_lowercase()) {
+            Some((si, factor)) if exponent == 1 => (si.to_string(), factor),
+            Some((si, factor)) => (format!("{si}^{exponent}"), factor.powi(exponent)),
+            None => (token.to_string(), 1.0),
+        }
+    };
+    match unit.split_once('/') {
+        Some((numerator, denominator)) => {
+            let (numerator, n) = token(numerator);
+            let (denominator, d) = token(denominator);
+            (format!("{numerator}/{denominator}"), n / d)
+        }
+        None => token(unit),
+    }
+}
@@ -2,7 +2,7 @@ use std::error::Error;
 
 use rasn_compiler::prelude::{ir::*, *};
 
-use crate::common::{to_ros_const_case, to_ros_title_case, IntegerTypeExt};
+use crate::common::{to_ros_title_case, unit_scale};
 use crate::conversion::{generate, Conversion, ConversionOptions};
 use crate::conversion::{template::*, utils::*};
 
@@ -13,10 +13,14 @@ impl Backend for Conversion {
         &self,
         tlds: Vec<ToplevelDefinition>,
     ) -> Result<GeneratedModule, GeneratorError> {
+        let options = ConversionOptions {
+            renames: self.options.renames.in_module(&tlds),
+            ..self.options.clone()
+        };
         let tlds = merge_tlds(tlds);
         let (pdus, warnings): (Vec<String>, Vec<Box<dyn Error>>) =
             tlds.into_iter().fold((vec![], vec![]), |mut acc, tld| {
-                match generate(&self.options, tld) {
+                match generate(&options, tld) {
                     Ok(s) => {
                         s.len().gt(&0).then(|| {
                             acc.0.push(format!(
@@ -108,8 +112,11 @@ pub fn merge_tlds(tlds: Vec<ToplevelDefinition>) -> Vec<ToplevelDefinition> {
             if let ASN1Type::Sequence(ref mut s) = t.ty {
                 s.members.iter_mut().for_each(|m| {
                     if let ASN1Type::InformationObjectFieldReference(ref mut r) = m.ty {
-                        if let Constraint::TableConstraint(ref mut c) = r.constraints[0] {
-                            if let ObjectSetValue::Reference(ref mut osvr) = c.object_set.values[0]
+                        if let Some(Constraint::TableConstraint(ref mut c)) =
+                            r.constraints.first_mut()
+                        {
+                            if let Some(ObjectSetValue::Reference(ref mut osvr)) =
+                                c.object_set.values.first_mut()
                             {
                                 object_sets
                                     .iter()
@@ -150,51 +157,24 @@ pub fn generate_typealias(
     }
 }
 
-pub fn generate_integer_value(tld: ToplevelValueDefinition) -> Result<String, GeneratorError> {
-    if let ASN1Value::LinkedIntValue { integer_type, .. } = tld.value {
-        let formatted_value = value_to_tokens(&tld.value, None)?;
-        let formatted_name = to_ros_const_case(&tld.name);
-        let ty = integer_type.to_str();
-        if tld.associated_type == INTEGER {
-            Ok(lazy_static_value_template(
-                &format_comments(&tld.comments)?,
-                &formatted_name,
-                "int64",
-                &formatted_value,
-            ))
-        } else if integer_type.is_unbounded() {
-            Ok(lazy_static_value_template(
-                &format_comments(&tld.comments)?,
-                &formatted_name,
-                ty,
-                &formatted_value,
-            ))
-        } else {
-            Ok(integer_value_template(
-                &format_comments(&tld.comments)?,
-                &formatted_name,
-                ty,
-                &formatted_value,
-            ))
-        }
-    } else {
-        Err(GeneratorError::new(
-            Some(ToplevelDefinition::Value(tld)),
-            "Expected INTEGER value top-level declaration",
-            GeneratorErrorType::Asn1TypeMismatch,
-        ))
-    }
-}
-
 pub fn generate_integer(
     options: &ConversionOptions,
     tld: ToplevelTypeDefinition,
 ) -> Result<String, GeneratorError> {
-    if let ASN1Type::Integer(_) = tld.ty {
+    if let ASN1Type::Integer(ref int) = tld.ty {
         Ok(integer_template(
             &options,
             &format_comments(&tld.comments)?,
             &to_ros_title_case(&tld.name),
+            &format_constraint_checks(
+                options,
+                &to_ros_title_case(options.renames.type_name(&tld.name)),
+                "value",
+                "in.value",
+                true,
+                &int.constraints,
+            )?,
+            unit_scale(&tld.comments, &int.distinguished_values).is_some(),
         ))
     } else {
         Err(GeneratorError::new(
@@ -209,11 +189,19 @@ pub fn generate_bit_string(
     options: &ConversionOptions,
     tld: ToplevelTypeDefinition,
 ) -> Result<String, GeneratorError> {
-    if let ASN1Type::BitString(_) = tld.ty {
+    if let ASN1Type::BitString(ref bitstr) = tld.ty {
         Ok(bit_string_template(
             &options,
             &format_comments(&tld.comments)?,
             &tld.name,
+            &format_constraint_checks(
+                options,
+                &to_ros_title_case(options.renames.type_name(&tld.name)),
+                "value",
+                "(in.value.size() * 8 - in.bits_unused)",
+                true,
+                &bitstr.constraints,
+            )?,
         ))
     } else {
         Err(GeneratorError::new(
@@ -282,154 +270,24 @@ pub fn generate_boolean(
     }
 }
 
-macro_rules! call_template {
-    ($fn:ident, $tld:ident, $($args:expr),*) => {
-        Ok($fn(
-            &format_comments(&$tld.comments)?,
-            (&$tld.name),
-            $($args),*
-        ))
-    };
-}
-
-macro_rules! assignment {
-    ($unformatted:expr, $inner:expr) => {{
-        let _ty = ($unformatted);
-        let _inner = $inner;
-        "quote!(#ty(#inner))".into()
-    }};
-}
-
-pub fn generate_value(tld: ToplevelValueDefinition) -> Result<String, GeneratorError> {
-    let ty = tld.associated_type.as_str();
-    match &tld.value {
-        ASN1Value::Null if ty == NULL => {
-            call_template!(
-                primitive_value_template,
-                tld,
-                "quote!(())".into(),
-                "quote!(())".into()
-            )
-        }
-        ASN1Value::Null => call_template!(
-            primitive_value_template,
-            tld,
-            &tld.associated_type,
-            assignment!(&tld.associated_type, "byte null 0".to_string())
-        ),
-        ASN1Value::Boolean(b) if ty == BOOLEAN => {
-            call_template!(primitive_value_template, tld, "bool", &b.to_string())
-        }
-        ASN1Value::Boolean(b) => call_template!(
-            primitive_value_template,
-            tld,
-            &tld.associated_type,
-            assignment!(&tld.associated_type, b.to_string())
-        ),
-        ASN1Value::LinkedIntValue { .. } => generate_integer_value(tld),
-        ASN1Value::BitString(_) if ty == BIT_STRING => todo!(),
-        ASN1Value::OctetString(_) if ty == OCTET_STRING => todo!(),
-        ASN1Value::Choice {
-            variant_name,
-            inner_value,
-            ..
-        } => {
-            if inner_value.is_const_type() {
-                call_template!(
-                    const_choice_value_template,
-                    tld,
-                    &tld.associated_type,
-                    variant_name,
-                    &value_to_tokens(inner_value, None)?
-                )
-            } else {
-                call_template!(
-                    choice_value_template,
-                    tld,
-                    &tld.associated_type,
-                    &variant_name,
-                    &value_to_tokens(inner_value, None)?
-                )
-            }
-        }
-        ASN1Value::EnumeratedValue {
-            enumerated,
-            enumerable,
-        } => call_template!(enum_value_template, tld, enumerated, enumerable),
-        ASN1Value::Time(_) if ty == GENERALIZED_TIME => todo!(),
-        ASN1Value::Time(_) if ty == UTC_TIME => todo!(),
-        ASN1Value::LinkedStructLikeValue(s) => {
-            let _members = s
-                .iter()
-                .map(|(_, _, val)| value_to_tokens(val.value(), None))
-                .collect::<Result<Vec<String>, _>>()?;
-            todo!()
-        }
-        ASN1Value::LinkedNestedValue { supertypes, value } => {
-            let parent = supertypes.last().map(|s| (s));
-            if value.is_const_type() {
-                call_template!(
-                    primitive_value_template,
-                    tld,
-                    &tld.associated_type,
-                    assignment!(&tld.associated_type, value_to_tokens(&tld.value, parent)?)
-                )
-            } else {
-                call_template!(
-                    lazy_static_value_template,
-                    tld,
-                    &tld.associated_type,
-                    assignment!(&tld.associated_type, value_to_tokens(&tld.value, parent)?)
-                )
-            }
-        }
-        ASN1Value::ObjectIdentifier(_) if ty == OBJECT_IDENTIFIER => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == NUMERIC_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == VISIBLE_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == IA5_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == UTF8_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == BMP_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == PRINTABLE_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == GENERAL_STRING => todo!(),
-        ASN1Value::LinkedArrayLikeValue(s) if ty.contains(SEQUENCE_OF) => {
-            let _item_type = format_sequence_or_set_of_item_type(
-                ty.replace(SEQUENCE_OF, "").trim().to_string(),
-                s.first().map(|i| &**i),
-            );
-            todo!()
-        }
-        ASN1Value::LinkedArrayLikeValue(s) if ty.contains(SET_OF) => {
-            let _item_type = format_sequence_or_set_of_item_type(
-                ty.replace(SET_OF, "").trim().to_string(),
-                s.first().map(|i| &**i),
-            );
-            todo!()
-        }
-        ASN1Value::BitString(_)
-        | ASN1Value::Time(_)
-        | ASN1Value::LinkedCharStringValue(_, _)
-        | ASN1Value::ObjectIdentifier(_)
-        | ASN1Value::LinkedArrayLikeValue(_)
-        | ASN1Value::ElsewhereDeclaredValue { .. }
-        | ASN1Value::OctetString(_) => call_template!(
-            lazy_static_value_template,
-            tld,
-            &tld.associated_type.to_string(),
-            assignment!(&tld.associated_type, value_to_tokens(&tld.value, None)?)
-        ),
-        _ => Ok("".to_string()),
-    }
+/// Values are declared as constants of the ROS messages and need no conversion
+pub fn generate_value(_tld: ToplevelValueDefinition) -> Result<String, GeneratorError> {
+    Ok("".into())
 }
 
 pub fn generate_any(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
-    Ok(any_template(&format_comments(&tld.comments)?, &tld.name))
+    Err(error!(
+        NotYetInplemented,
+        "{} types are currently unsupported!",
+        tld.ty.as_str()
+    ))
 }
 
 pub fn generate_generalized_time(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::GeneralizedTime(_) = &tld.ty {
-        Ok(generalized_time_template(
-            &format_comments(&tld.comments)?,
-            &tld.name,
+        Err(error!(
+            NotYetInplemented,
+            "GeneralizedTime types are currently unsupported!"
         ))
     } else {
         Err(GeneratorError::new(
@@ -442,9 +300,9 @@ pub fn generate_generalized_time(tld: ToplevelTypeDefinition) -> Result<String,
 
 pub fn generate_utc_time(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::UTCTime(_) = &tld.ty {
-        Ok(utc_time_template(
-            &format_comments(&tld.comments)?,
-            &tld.name,
+        Err(error!(
+            NotYetInplemented,
+            "UTCTime types are currently unsupported!"
         ))
     } else {
         Err(GeneratorError::new(
@@ -457,7 +315,10 @@ pub fn generate_utc_time(tld: ToplevelTypeDefinition) -> Result<String, Generato
 
 pub fn generate_oid(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::ObjectIdentifier(_oid) = &tld.ty {
-        Ok(oid_template(&format_comments(&tld.comments)?, &tld.name))
+        Err(error!(
+            NotYetInplemented,
+            "OBJECT IDENTIFIER types are currently unsupported!"
+        ))
     } else {
         Err(GeneratorError::new(
             Some(ToplevelDefinition::Type(tld)),
@@ -469,7 +330,10 @@ pub fn generate_oid(tld: ToplevelTypeDefinition) -> Result<String, GeneratorErro
 
 pub fn generate_null(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::Null = tld.ty {
-        Ok(null_template(&format_comments(&tld.comments)?, &tld.name))
+        Err(error!(
+            NotYetInplemented,
+            "NULL types are currently unsupported!"
+        ))
     } else {
         Err(GeneratorError::new(
             Some(ToplevelDefinition::Type(tld)),
@@ -503,7 +367,7 @@ pub fn generate_choice(
     tld: ToplevelTypeDefinition,
 ) -> Result<String, GeneratorError> {
     if let ASN1Type::Choice(ref choice) = tld.ty {
-        let members = get_choice_members_names(choice);
+        let members = get_choice_members_names(choice)?;
         Ok(choice_template(
             &options,
             &format_comments(&tld.comments)?,
@@ -525,7 +389,7 @@ pub fn generate_sequence_or_set(
 ) -> Result<String, GeneratorError> {
     match tld.ty {
         ASN1Type::Sequence(ref seq) | ASN1Type::Set(ref seq) => {
-            let members = get_sequence_or_set_members_names(seq);
+            let members = get_sequence_or_set_members_names(seq)?;
             Ok(sequence_or_set_template(
                 options,
                 &format_comments(&tld.comments)?,
@@ -574,16 +438,24 @@ pub fn generate_sequence_or_set_of(
         )?),
     }
     .unwrap_or_default();
-    let member_type = match seq_or_set_of.element_type.as_ref() {
-        ASN1Type::ElsewhereDeclaredType(d) => d.identifier.clone(),
-        _ => format!("Anonymous{}", &tld.name),
-    };
+    let member = get_sequence_or_set_of_member_name_type(seq_or_set_of, &tld.name)?;
+    let element_c_type = element_c_type(seq_or_set_of, &member)?;
+    let constraint_checks = format_constraint_checks(
+        options,
+        &to_ros_title_case(options.renames.type_name(&tld.name)),
+        "array",
+        "in.array.size()",
+        true,
+        &seq_or_set_of.constraints,
+    )?;
     Ok(sequence_or_set_of_template(
         &options,
         is_set_of,
         &format_comments(&tld.comments)?,
         &tld.name,
         &anonymous_item,
-        &member_type,
+        &member,
+        &element_c_type,
+        &constraint_checks,
     ))
 }
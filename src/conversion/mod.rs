@@ -1,20 +1,49 @@
 use rasn_compiler::prelude::{ir::ASN1Type, *};
 
+use crate::common::to_ros_title_case;
+use crate::renames::Renames;
+
 mod builder;
+mod primitives;
 mod template;
 mod utils;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Conversion {
     options: ConversionOptions,
 }
+#[derive(Clone)]
 pub struct ConversionOptions {
     main_pdu: String,
+    check_constraints: bool,
+    ros_target: RosTarget,
+    type_adapters: bool,
+    unit_helpers: bool,
+    renames: Renames,
+}
+/// ROS distributions that the conversion headers are generated for. The headers only differ in
+/// the message includes and namespace, as the messages use unbounded arrays and `string`, which
+/// are `std::vector` and `std::string` in both distributions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RosTarget {
+    /// ROS 1 only
+    Ros1,
+    /// ROS 2 only
+    Ros2,
+    /// Both, selected with `#ifdef ROS1`
+    #[default]
+    Both,
 }
 impl Default for ConversionOptions {
     fn default() -> Self {
         Self {
             main_pdu: "pdu".into(),
+            check_constraints: false,
+            ros_target: RosTarget::default(),
+            type_adapters: false,
+            unit_helpers: false,
+            renames: Renames::default(),
         }
     }
 }
@@ -23,6 +52,46 @@ impl Conversion {
         self.options.main_pdu = main_pdu_name.to_owned();
         self
     }
+    /// Reject ROS values outside the ASN.1 constraints in `toStruct_*`
+    pub fn set_check_constraints(mut self, check_constraints: bool) -> Self {
+        self.options.check_constraints = check_constraints;
+        self
+    }
+    pub fn set_ros_target(mut self, ros_target: RosTarget) -> Self {
+        self.options.ros_target = ros_target;
+        self
+    }
+    /// Emit `rclcpp::TypeAdapter` specializations for the ROS 2 headers
+    pub fn set_type_adapters(mut self, type_adapters: bool) -> Self {
+        self.options.type_adapters = type_adapters;
+        self
+    }
+    /// Emit `getSI_*`/`setSI_*` helpers for INTEGER types with a `SCALE` and `UNIT`
+    pub fn set_unit_helpers(mut self, unit_helpers: bool) -> Self {
+        self.options.unit_helpers = unit_helpers;
+        self
+    }
+    /// Rename ROS messages and fields, while still addressing the original asn1c types and members
+    pub fn set_renames(mut self, renames: Renames) -> Self {
+        self.options.renames = renames;
+        self
+    }
+    /// Generates the umbrella header `convert{Pdu}.h`, as a `(file name, content)` pair,
+    /// including the conversion headers of all given types.
+    /// Returns `None` if one of the types is the main PDU, whose header is the entry point then.
+    pub fn generate_umbrella(&self, type_names: &[String]) -> Option<(String, String)> {
+        if type_names
+            .iter()
+            .any(|ty| ty.eq_ignore_ascii_case(&self.options.main_pdu))
+        {
+            return None;
+        }
+        let name = to_ros_title_case(&self.options.main_pdu);
+        Some((
+            format!("convert{name}.h"),
+            template::umbrella_template(&self.options.main_pdu, &name, type_names),
+        ))
+    }
 }
 
 use builder::*;
@@ -30,6 +99,17 @@ use builder::*;
 fn generate(
     options: &ConversionOptions,
     tld: ToplevelDefinition,
+) -> Result<String, GeneratorError> {
+    // report unsupported constructs with the name of the offending type
+    generate_definition(options, tld.clone()).map_err(|mut e| {
+        e.top_level_declaration.get_or_insert(tld);
+        e
+    })
+}
+
+fn generate_definition(
+    options: &ConversionOptions,
+    tld: ToplevelDefinition,
 ) -> Result<String, GeneratorError> {
     match tld {
         ToplevelDefinition::Type(t) => {
@@ -50,7 +130,11 @@ fn generate(
                 ASN1Type::ElsewhereDeclaredType(_) => generate_typealias(&options, t),
                 ASN1Type::Choice(_) => generate_choice(&options, t),
                 ASN1Type::OctetString(_) => generate_octet_string(&options, t),
-                ASN1Type::Time(_) => unimplemented!("rasn does not support TIME types yet!"),
+                ASN1Type::Time(_) => Err(GeneratorError {
+                    kind: GeneratorErrorType::NotYetInplemented,
+                    details: "Time types are currently unsupported!".into(),
+                    top_level_declaration: None,
+                }),
                 ASN1Type::Real(_) => Err(GeneratorError {
                     kind: GeneratorErrorType::NotYetInplemented,
                     details: "Real types are currently unsupported!".into(),
@@ -62,7 +146,11 @@ fn generate(
                 | ASN1Type::External => generate_any(t),
                 ASN1Type::GeneralizedTime(_) => generate_generalized_time(t),
                 ASN1Type::UTCTime(_) => generate_utc_time(t),
-                ASN1Type::ChoiceSelectionType(_) => unreachable!(),
+                ASN1Type::ChoiceSelectionType(_) => Err(GeneratorError {
+                    kind: GeneratorErrorType::NotYetInplemented,
+                    details: "Choice selection types are currently unsupported!".into(),
+                    top_level_declaration: None,
+                }),
             }
         }
         ToplevelDefinition::Value(v) => generate_value(v),
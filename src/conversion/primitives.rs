@@ -0,0 +1,125 @@
+use rasn_compiler::prelude::ir::CharacterStringType;
+
+use crate::conversion::utils::string_type;
+use crate::conversion::Conversion;
+
+const PRIMITIVE_TEMPLATE: &str = r#"//// PRIMITIVE {c_type}
+
+#pragma once
+
+#include <cstring>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+// Include the asn1c header declaring {c_type}_t, e.g. <etsi_its_cam_coding/{c_type}.h>, first
+
+
+namespace etsi_its_primitives_conversion {
+
+{functions}
+
+}"#;
+
+const INTEGER_FUNCTIONS: &str = r#"template <typename T>
+inline void toRos_INTEGER(const INTEGER_t& in, T& out) {
+  long value;
+  if (asn_INTEGER2long(&in, &value)) throw std::range_error("Failed to convert INTEGER_t to long");
+  out = value;
+}
+
+template <typename T>
+inline void toRos_INTEGER(const long& in, T& out) {
+  out = in;
+}
+
+template <typename T>
+inline void toRos_INTEGER(const unsigned long& in, T& out) {
+  out = in;
+}
+
+template <typename T>
+inline void toStruct_INTEGER(const T& in, INTEGER_t& out) {
+  if (asn_long2INTEGER(&out, in)) throw std::range_error("Failed to convert long to INTEGER_t");
+}
+
+template <typename T>
+inline void toStruct_INTEGER(const T& in, long& out) {
+  out = in;
+}
+
+template <typename T>
+inline void toStruct_INTEGER(const T& in, unsigned long& out) {
+  out = in;
+}"#;
+
+const BOOLEAN_FUNCTIONS: &str = r#"template <typename T>
+inline void toRos_BOOLEAN(const BOOLEAN_t& in, T& out) {
+  out = in;
+}
+
+template <typename T>
+inline void toStruct_BOOLEAN(const T& in, BOOLEAN_t& out) {
+  out = in;
+}"#;
+
+/// OCTET STRING, BIT STRING and character strings share the `buf`/`size` layout of asn1c
+const BUFFER_FUNCTIONS: &str = r#"template <typename T>
+inline void toRos_{c_type}(const {c_type}_t& in, T& out) {
+  out.assign(in.buf, in.buf + in.size);
+}
+
+template <typename T>
+inline void toStruct_{c_type}(const T& in, {c_type}_t& out) {
+  out.size = in.size();
+  out.buf = (uint8_t*) calloc(out.size + 1, sizeof(uint8_t));
+  if (!out.buf) throw std::invalid_argument("Failed to allocate {c_type}");
+  std::memcpy(out.buf, in.data(), out.size);
+}"#;
+
+const CHARACTER_STRING_TYPES: [CharacterStringType; 11] = [
+    CharacterStringType::NumericString,
+    CharacterStringType::VisibleString,
+    CharacterStringType::IA5String,
+    CharacterStringType::TeletexString,
+    CharacterStringType::VideotexString,
+    CharacterStringType::GraphicString,
+    CharacterStringType::GeneralString,
+    CharacterStringType::UniversalString,
+    CharacterStringType::UTF8String,
+    CharacterStringType::BMPString,
+    CharacterStringType::PrintableString,
+];
+
+pub fn primitive_template(c_type: &str, functions: &str) -> String {
+    PRIMITIVE_TEMPLATE
+        .replace("{functions}", functions)
+        .replace("{c_type}", c_type)
+}
+
+impl Conversion {
+    /// Generates the `etsi_its_primitives_conversion` headers, as `(file name, content)` pairs,
+    /// for every primitive that the generated conversion headers can reference
+    pub fn generate_primitives(&self) -> Vec<(String, String)> {
+        let mut primitives = vec![
+            ("INTEGER".to_string(), INTEGER_FUNCTIONS.to_string()),
+            ("BOOLEAN".to_string(), BOOLEAN_FUNCTIONS.to_string()),
+            ("OCTET_STRING".to_string(), BUFFER_FUNCTIONS.to_string()),
+            ("BIT_STRING".to_string(), BUFFER_FUNCTIONS.to_string()),
+        ];
+        primitives.extend(
+            CHARACTER_STRING_TYPES
+                .iter()
+                .map(|ty| (string_type(ty).unwrap(), BUFFER_FUNCTIONS.to_string())),
+        );
+        primitives
+            .into_iter()
+            .map(|(c_type, functions)| {
+                (
+                    format!("convert{c_type}.h"),
+                    primitive_template(&c_type, &functions),
+                )
+            })
+            .collect()
+    }
+}
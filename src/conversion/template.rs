@@ -1,29 +1,31 @@
-use crate::common::{to_ros_const_case, to_ros_snake_case, to_ros_title_case};
-use crate::conversion::utils::{InnerTypes, NameType, NamedSeqMember};
-use crate::conversion::ConversionOptions;
+use crate::common::{ros_includes, to_ros_const_case, to_ros_snake_case, to_ros_title_case};
+use crate::conversion::utils::{
+    format_member_constraint_checks, InnerTypes, NameType, NamedSeqMember,
+};
+use crate::conversion::{ConversionOptions, RosTarget};
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 const CONVERSION_TEMPLATE: &str = r#"//// {asn1_type} {name}
 {comments}
 
 #pragma once
 
-#include <stdexcept>
+#include <memory>
+#include <stdexcept>{codec_includes}
 
 #include <etsi_its_{pdu}_coding/{c_type}.h>
 {c_includes}
-#ifdef ROS1
-{ros1_includes}
-namespace {pdu}_msgs = etsi_its_{pdu}_msgs;
-#else
-{ros2_includes}
-namespace {pdu}_msgs = etsi_its_{pdu}_msgs::msg;
-#endif
+{ros_includes}
 
 
 namespace etsi_its_{pdu}_conversion {
 
+struct Free_{type} {
+  void operator()({c_type}_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_{c_type}, ptr); }
+};
+using {type}_ptr = std::unique_ptr<{c_type}_t, Free_{type}>;
+
 void toRos_{type}(const {c_type}_t& in, {pdu}_msgs::{ros_type}& out) {
   {to_ros_members}
 }
@@ -31,67 +33,180 @@ void toRos_{type}(const {c_type}_t& in, {pdu}_msgs::{ros_type}& out) {
 void toStruct_{type}(const {pdu}_msgs::{ros_type}& in, {c_type}_t& out) {
   memset(&out, 0, sizeof({c_type}_t));
 
-  {to_c_members}
+  try {
+    {to_c_members}
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_{c_type}, &out);
+    memset(&out, 0, sizeof({c_type}_t));
+    throw;
+  }
+}
+
+{type}_ptr toStruct_{type}(const {pdu}_msgs::{ros_type}& in) {
+  {type}_ptr out(({c_type}_t*) calloc(1, sizeof({c_type}_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_{type}(in, *out);
+  return out;
+}
+{codec}{helpers}
+}{type_adapter}"#;
+
+/// Getter and setter of the value in the unit declared by the `SCALE` and `UNIT` constants
+const UNIT_HELPERS_TEMPLATE: &str = r#"
+double getSI_{type}(const {pdu}_msgs::{ros_type}& in) {
+  return in.value * {pdu}_msgs::{ros_type}::SCALE;
 }
 
-}"#;
+void setSI_{type}({pdu}_msgs::{ros_type}& out, double value) {
+  out.value = static_cast<decltype(out.value)>(std::llround(value / {pdu}_msgs::{ros_type}::SCALE));
+}
+"#;
+
+/// `rclcpp::TypeAdapter` specialization, ROS 2 only
+const TYPE_ADAPTER_TEMPLATE: &str = r#"
+
+#include <rclcpp/type_adapter.hpp>
+
+template <>
+struct rclcpp::TypeAdapter<{c_type}_t, {pdu}_msgs::{ros_type}> {
+  using is_specialized = std::true_type;
+  using custom_type = {c_type}_t;
+  using ros_message_type = {pdu}_msgs::{ros_type};
+
+  static void convert_to_ros_message(const custom_type& source, ros_message_type& destination) {
+    etsi_its_{pdu}_conversion::toRos_{type}(source, destination);
+  }
+
+  static void convert_to_custom(const ros_message_type& source, custom_type& destination) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_{c_type}, &destination);
+    etsi_its_{pdu}_conversion::toStruct_{type}(source, destination);
+  }
+};"#;
+
+/// UPER codec helpers, generated for the main PDU only
+const CODEC_TEMPLATE: &str = r#"
+{pdu}_msgs::{ros_type} decodeToRos(const uint8_t* buffer, size_t size) {
+  {c_type}_t* raw = nullptr;
+  asn_dec_rval_t ret = uper_decode_complete(nullptr, &asn_DEF_{c_type}, (void**) &raw, buffer, size);
+  {type}_ptr in(raw);
+  if (ret.code == RC_WMORE) throw std::invalid_argument("Failed to decode {c_type}: more data expected");
+  if (ret.code != RC_OK) throw std::invalid_argument("Failed to decode {c_type}: malformed data at byte " + std::to_string(ret.consumed));
+  {pdu}_msgs::{ros_type} out;
+  toRos_{type}(*in, out);
+  return out;
+}
 
+std::vector<uint8_t> encodeFromRos(const {pdu}_msgs::{ros_type}& in) {
+  {type}_ptr raw = toStruct_{type}(in);
+  char error_buffer[256];
+  size_t error_length = sizeof(error_buffer);
+  if (asn_check_constraints(&asn_DEF_{c_type}, raw.get(), error_buffer, &error_length)) {
+    throw std::invalid_argument("Invalid {c_type}: " + std::string(error_buffer, error_length));
+  }
+  asn_encode_to_new_buffer_result_t ret = asn_encode_to_new_buffer(nullptr, ATS_UNALIGNED_BASIC_PER, &asn_DEF_{c_type}, raw.get());
+  if (ret.result.encoded == -1) {
+    throw std::invalid_argument("Failed to encode {c_type}: " + std::string(ret.result.failed_type ? ret.result.failed_type->name : "unknown type"));
+  }
+  std::vector<uint8_t> out((uint8_t*) ret.buffer, (uint8_t*) ret.buffer + ret.result.encoded);
+  free(ret.buffer);
+  return out;
+}
+"#;
+
+#[allow(clippy::too_many_arguments)]
 pub fn conversion_template(
     comments: &str,
-    pdu: &str,
+    options: &ConversionOptions,
     includes: &Vec<NameType>,
     name: &str,
     asn1_type: &str,
     to_ros_members: &str,
     to_c_members: &str,
+    helpers: &str,
 ) -> String {
-    let c_includes = includes
+    let pdu = &options.main_pdu;
+    let mut coding_includes = BTreeSet::new();
+    let mut conversion_includes = BTreeSet::new();
+    includes
         .iter()
-        .map(|member| {
+        .filter(|member| member.is_primitive || member.ty != name)
+        .for_each(|member| {
             if !member.is_primitive {
-                format!(
+                conversion_includes.insert(format!(
                     "#include <etsi_its_{pdu}_conversion/convert{dep}.h>",
                     pdu = pdu,
                     dep = member.ty
-                )
+                ));
             } else {
-                format!(
+                coding_includes.insert(format!(
                     "#include <etsi_its_{pdu}_coding/{dep}.h>",
                     pdu = pdu,
                     dep = member.ty
-                ) + "\n"
-                    + &format!(
-                        "#include <etsi_its_primitives_conversion/convert{dep}.h>",
-                        dep = member.ty
-                    )
+                ));
+                conversion_includes.insert(format!(
+                    "#include <etsi_its_primitives_conversion/convert{dep}.h>",
+                    dep = member.ty
+                ));
             }
-        })
+        });
+    let c_includes = coding_includes
+        .into_iter()
+        .chain(conversion_includes)
         .collect::<Vec<String>>()
         .join("\n");
-    let ros1_includes = format!(
-        "#include <etsi_its_{pdu}_msgs/{ros_fn}.hpp>",
-        pdu = pdu,
-        ros_fn = to_ros_title_case(name)
-    );
-    let ros2_includes = format!(
-        "#include <etsi_its_{pdu}_msgs/msg/{ros_fn}.hpp>",
-        pdu = pdu,
-        ros_fn = to_ros_snake_case(name)
-    );
+    let ros_name = options.renames.type_name(name);
+    let ros_includes = ros_includes(pdu, options.ros_target, &[ros_name]);
+
+    let (mut codec_includes, codec) = if name.eq_ignore_ascii_case(pdu) {
+        (
+            String::from("\n#include <string>\n#include <vector>"),
+            CODEC_TEMPLATE,
+        )
+    } else {
+        (String::new(), "")
+    };
+    if helpers.contains("std::llround") {
+        codec_includes.insert_str(0, "\n#include <cmath>");
+    }
+
+    let type_adapter = match (options.type_adapters, options.ros_target) {
+        (false, _) | (true, RosTarget::Ros1) => String::new(),
+        (true, RosTarget::Ros2) => TYPE_ADAPTER_TEMPLATE.to_string(),
+        (true, RosTarget::Both) => format!("\n\n#ifndef ROS1{TYPE_ADAPTER_TEMPLATE}\n#endif"),
+    };
 
     CONVERSION_TEMPLATE
+        .replace("{type_adapter}", &type_adapter)
+        .replace("{codec_includes}", &codec_includes)
+        .replace("{codec}", codec)
+        .replace("{helpers}", helpers)
         .replace("{comments}", comments)
         .replace("{c_includes}", &c_includes)
-        .replace("{ros1_includes}", &ros1_includes)
-        .replace("{ros2_includes}", &ros2_includes)
+        .replace("{ros_includes}", &ros_includes)
         .replace("{asn1_type}", asn1_type)
         .replace("{name}", name)
         .replace("{c_type}", name)
         .replace("{type}", name)
-        .replace("{ros_type}", &to_ros_title_case(name))
+        .replace("{ros_type}", &to_ros_title_case(ros_name))
         .replace("{pdu}", pdu)
         .replace("{to_ros_members}", &to_ros_members)
-        .replace("{to_c_members}", &to_c_members)
+        .replace("{to_c_members}", &to_c_members.replace('\n', "\n  "))
+}
+
+/// Prefixes the constraint violations raised by `call` with the member `path`
+fn with_path(options: &ConversionOptions, call: String, path: &str) -> String {
+    if options.check_constraints {
+        format!(
+            "try {{\n    \
+             {call}\n  \
+             }} catch (const std::out_of_range& e) {{\n    \
+             throw std::out_of_range({path} + std::string(e.what()));\n  \
+             }}",
+            call = call.replace('\n', "\n  ")
+        )
+    } else {
+        call
+    }
 }
 
 pub fn typealias_template(
@@ -102,91 +217,96 @@ pub fn typealias_template(
 ) -> String {
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![NameType {
             name: name.to_string(),
             ty: alias.to_string(),
             is_primitive: false,
             inner_types: None,
+            bounds: None,
         }],
         name,
         "TYPEALIAS",
         &format!("toRos_{alias}(in, out.value);"),
         &format!("toStruct_{alias}(in.value, out);"),
+        "",
     )
 }
 
-pub fn integer_value_template(_comments: &str, _name: &str, _vtype: &str, _value: &str) -> String {
-    todo!()
-}
-
-pub fn lazy_static_value_template(
-    _comments: &str,
-    _name: &str,
-    _vtype: &str,
-    _value: &str,
+pub fn integer_template(
+    options: &ConversionOptions,
+    comments: &str,
+    name: &str,
+    constraint_checks: &str,
+    has_unit: bool,
 ) -> String {
-    todo!()
-}
-
-pub fn integer_template(options: &ConversionOptions, comments: &str, name: &str) -> String {
+    let helpers = if options.unit_helpers && has_unit {
+        UNIT_HELPERS_TEMPLATE
+    } else {
+        ""
+    };
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![NameType {
             name: name.to_string(),
             ty: "INTEGER".to_string(),
             is_primitive: true,
             inner_types: None,
+            bounds: None,
         }],
         name,
         "INTEGER",
         "etsi_its_primitives_conversion::toRos_INTEGER(in, out.value);",
-        "etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);",
+        &format!("{constraint_checks}etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);"),
+        helpers,
     )
 }
 
-pub fn generalized_time_template(_comments: &str, _name: &str) -> String {
-    todo!();
-}
-
-pub fn utc_time_template(_comments: &str, _name: &str) -> String {
-    todo!();
-}
-
-pub fn bit_string_template(options: &ConversionOptions, comments: &str, name: &str) -> String {
+pub fn bit_string_template(
+    options: &ConversionOptions,
+    comments: &str,
+    name: &str,
+    constraint_checks: &str,
+) -> String {
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![NameType {
             name: name.to_string(),
             ty: "BIT_STRING".to_string(),
             is_primitive: true,
             inner_types: None,
+            bounds: None,
         }],
         name,
         "BIT-STRING",
         "etsi_its_primitives_conversion::toRos_BIT_STRING(in, out.value);\n  \
          out.bits_unused = in.bits_unused;",
-        "etsi_its_primitives_conversion::toStruct_BIT_STRING(in.value, out);\n  \
-         out.bits_unused = in.bits_unused;",
+        &format!(
+            "{constraint_checks}etsi_its_primitives_conversion::toStruct_BIT_STRING(in.value, out);\n  \
+             out.bits_unused = in.bits_unused;"
+        ),
+        "",
     )
 }
 
 pub fn octet_string_template(options: &ConversionOptions, comments: &str, name: &str) -> String {
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![NameType {
             name: name.to_string(),
             ty: "OCTET_STRING".to_string(),
             is_primitive: true,
             inner_types: None,
+            bounds: None,
         }],
         name,
         "OCTET-STRING",
         "etsi_its_primitives_conversion::toRos_OCTET_STRING(in, out.value);",
         "etsi_its_primitives_conversion::toStruct_OCTET_STRING(in.value, out);",
+        "",
     )
 }
 
@@ -198,88 +318,54 @@ pub fn char_string_template(
 ) -> String {
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![NameType {
             name: name.to_string(),
             ty: string_type.to_string(),
             is_primitive: true,
             inner_types: None,
+            bounds: None,
         }],
         name,
         string_type,
         &format!("etsi_its_primitives_conversion::toRos_{string_type}(in, out.value);"),
         &format!("etsi_its_primitives_conversion::toStruct_{string_type}(in.value, out);"),
+        "",
     )
 }
 
 pub fn boolean_template(options: &ConversionOptions, comments: &str, name: &str) -> String {
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![NameType {
             name: name.to_string(),
             ty: "BOOLEAN".to_string(),
             is_primitive: true,
             inner_types: None,
+            bounds: None,
         }],
         name,
         "BOOLEAN",
         "etsi_its_primitives_conversion::toRos_BOOLEAN(in, out.value);",
         "etsi_its_primitives_conversion::toStruct_BOOLEAN(in.value, out);",
+        "",
     )
 }
 
-pub fn primitive_value_template(
-    _comments: &str,
-    _name: &str,
-    _type_name: &str,
-    _assignment: &str,
-) -> String {
-    todo!();
-}
-
-pub fn enum_value_template(
-    _comments: &str,
-    _name: &str,
-    _enumerated: &str,
-    _enumeral: &str,
-) -> String {
-    todo!();
-}
-
-pub fn null_template(_comments: &str, _name: &str) -> String {
-    todo!();
-}
-
-pub fn any_template(_comments: &str, _name: &str) -> String {
-    todo!();
-}
-
-pub fn oid_template(_comments: &str, _name: &str) -> String {
-    todo!();
-}
-
 pub fn enumerated_template(options: &ConversionOptions, comments: &str, name: &str) -> String {
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &vec![],
         name,
         "ENUMERATED",
         "out.value = in;",
         "out = in.value;",
+        "",
     )
 }
 
-pub fn _sequence_or_set_value_template(
-    _comments: &str,
-    _name: &str,
-    _vtype: &str,
-    _members: &str,
-) -> String {
-    todo!()
-}
-
 #[allow(clippy::too_many_arguments)]
 pub fn sequence_or_set_template(
     options: &ConversionOptions,
@@ -287,6 +373,9 @@ pub fn sequence_or_set_template(
     name: &str,
     members: Vec<NamedSeqMember>,
 ) -> String {
+    let ros_name = |member: &NamedSeqMember| {
+        to_ros_snake_case(options.renames.member_name(name, &member.name_type.name))
+    };
     let links: HashMap<String, &NamedSeqMember> = members
         .iter()
         .filter_map(|m| {
@@ -319,7 +408,7 @@ pub fn sequence_or_set_template(
                             pdu = &options.main_pdu,
                             linked_with = links.get(&c.linked_with).unwrap().name_type.ty,
                             c_field_name = member.name_type.name,
-                            r_field_name = &to_ros_snake_case(&member.name_type.name),
+                            r_field_name = &ros_name(member),
                             ty = im.ty,
                             c_member = to_ros_title_case(&im.name),
                             r_member = to_ros_snake_case(&im.name),
@@ -339,55 +428,78 @@ pub fn sequence_or_set_template(
         }
     };
 
-    let to_ros_conversion_call = |member: &NamedSeqMember| -> String {
+    let to_ros_conversion_call = |member: &NamedSeqMember, c: &str, r: &str| -> String {
+        let deref = if member.is_pointer() { "*" } else { "" };
         if !member.name_type.is_primitive {
             if member.name_type.inner_types.is_some() {
                 // TODO optional type with inner_types
                 to_ros_inner_members(&member)
             } else {
                 format!(
-                    "toRos_{ty}({deref}in.{c_member}, out.{r_member});",
+                    "toRos_{ty}({deref}{c}{c_member}, {r}{r_member});",
                     ty = member.name_type.ty,
-                    deref = if member.is_optional { "*" } else { "" },
                     c_member = member.name_type.name,
-                    r_member = to_ros_snake_case(&member.name_type.name)
+                    r_member = ros_name(member)
                 )
             }
         } else {
             format!(
-                "etsi_its_primitives_conversion::toRos_{ty}({deref}in.{c_member}, out.{r_member});",
+                "etsi_its_primitives_conversion::toRos_{ty}({deref}{c}{c_member}, {r}{r_member});",
                 ty = member.name_type.ty,
-                deref = if member.is_optional { "*" } else { "" },
                 c_member = member.name_type.name,
-                r_member = to_ros_snake_case(&member.name_type.name)
+                r_member = ros_name(member)
             )
         }
     };
-    let to_ros_fmt_member = |member: &NamedSeqMember| -> String {
-        if member.is_optional {
+    let to_ros_fmt_member = |member: &NamedSeqMember, c: &str, r: &str| -> String {
+        if member.is_pointer() {
             format!(
-                "if (in.{c_member}) {{\n    \
+                "if ({c}{c_member}) {{\n    \
                      {conversion}\n  \
                      {present}\
                      }}",
                 c_member = member.name_type.name,
-                conversion = to_ros_conversion_call(&member),
-                present = if !member.has_default {
+                conversion = to_ros_conversion_call(member, c, r),
+                present = if member.has_present_flag() {
                     format!(
-                        "  out.{r_member}_is_present = true;\n  ",
-                        r_member = to_ros_snake_case(&member.name_type.name)
+                        "  {r}{r_member}_is_present = true;\n  ",
+                        r_member = ros_name(member)
                     )
                 } else {
                     "".to_string()
                 }
             )
         } else {
-            to_ros_conversion_call(&member)
+            to_ros_conversion_call(member, c, r)
         }
     };
     let to_ros_members = members
         .iter()
-        .map(|member| to_ros_fmt_member(&member))
+        .map(|member| {
+            if let Some(group) = &member.extension_group {
+                let r_group = ros_name(member);
+                format!(
+                    "if (in.{c_group}) {{\n    \
+                     {conversions}\n    \
+                     out.{r_group}_is_present = true;\n  \
+                     }}",
+                    c_group = group.c_name,
+                    conversions = group
+                        .members
+                        .iter()
+                        .map(|m| to_ros_fmt_member(
+                            m,
+                            &format!("in.{}->", group.c_name),
+                            &format!("out.{r_group}.")
+                        )
+                        .replace('\n', "\n  "))
+                        .collect::<Vec<String>>()
+                        .join("\n    "),
+                )
+            } else {
+                to_ros_fmt_member(member, "in.", "out.")
+            }
+        })
         .collect::<Vec<String>>()
         .join("\n  ");
 
@@ -404,7 +516,7 @@ pub fn sequence_or_set_template(
                             pdu = &options.main_pdu,
                             linked_with = links.get(&c.linked_with).unwrap().name_type.ty,
                             c_field_name = member.name_type.name,
-                            r_field_name = &to_ros_snake_case(&member.name_type.name),
+                            r_field_name = &ros_name(member),
                             ty = im.ty,
                             c_member = to_ros_title_case(&im.name),
                             r_member = to_ros_snake_case(&im.name),
@@ -416,7 +528,7 @@ pub fn sequence_or_set_template(
                 "switch (in.{field_name}.choice.value) {{\n\
                     {cases}\n  \
                     }}",
-                field_name = to_ros_snake_case(&member.name_type.name),
+                field_name = ros_name(member),
                 cases = cases
             )
         } else {
@@ -424,63 +536,101 @@ pub fn sequence_or_set_template(
         }
     };
 
-    let to_c_conversion_call = |member: &NamedSeqMember| -> String {
+    let to_c_conversion_call = |member: &NamedSeqMember, r: &str, c: &str| -> String {
+        let deref = if member.is_pointer() { "*" } else { "" };
         if !member.name_type.is_primitive {
             if member.name_type.inner_types.is_some() {
                 // TODO optional type with inner_types
                 to_c_inner_members(&member)
             } else {
-                format!(
-                    "toStruct_{ty}(in.{r_member}, {deref}out.{c_member});",
-                    ty = member.name_type.ty,
-                    deref = if member.is_optional { "*" } else { "" },
-                    c_member = member.name_type.name,
-                    r_member = to_ros_snake_case(&member.name_type.name)
+                with_path(
+                    options,
+                    format!(
+                        "toStruct_{ty}({r}{r_member}, {deref}{c}{c_member});",
+                        ty = member.name_type.ty,
+                        c_member = member.name_type.name,
+                        r_member = ros_name(member)
+                    ),
+                    &format!("\"{}{}.\"", r.trim_start_matches("in."), ros_name(member)),
                 )
             }
         } else {
-            format!("etsi_its_primitives_conversion::toStruct_{ty}(in.{r_member}, {deref}out.{c_member});", 
+            let checks = format_member_constraint_checks(
+                options,
+                &member.name_type,
+                &format!("{}{}", r.trim_start_matches("in."), ros_name(member)),
+                &format!("{r}{}", ros_name(member)),
+            );
+            format!("{checks}etsi_its_primitives_conversion::toStruct_{ty}({r}{r_member}, {deref}{c}{c_member});", 
                 ty = member.name_type.ty,
-                deref = if member.is_optional { "*" } else { "" },
                 c_member = member.name_type.name,
-                r_member = to_ros_snake_case(&member.name_type.name)
+                r_member = ros_name(member)
             )
         }
     };
-    let to_c_fmt_member = |member: &NamedSeqMember| -> String {
-        if member.is_optional {
-            if !member.has_default {
+    let to_c_fmt_member = |member: &NamedSeqMember, r: &str, c: &str| -> String {
+        if member.is_pointer() {
+            if member.has_present_flag() {
                 format!(
-                    "if (in.{r_member}_is_present) {{\n    \
-                         out.{c_member} = ({ty}_t*) calloc(1, sizeof({ty}_t));\n    \
+                    "if ({r}{r_member}_is_present) {{\n    \
+                         {c}{c_member} = (decltype({c}{c_member})) calloc(1, sizeof(*{c}{c_member}));\n    \
+                         if (!{c}{c_member}) throw std::bad_alloc();\n    \
                          {conversion}\n  \
                          }}",
-                    ty = member.name_type.ty,
                     c_member = member.name_type.name,
-                    conversion = to_c_conversion_call(&member),
-                    r_member = to_ros_snake_case(&member.name_type.name)
+                    conversion = to_c_conversion_call(member, r, c).replace('\n', "\n  "),
+                    r_member = ros_name(member)
                 )
             } else {
                 format!(
-                    "out.{c_member} = ({ty}_t*) calloc(1, sizeof({ty}_t));\n  \
+                    "{c}{c_member} = (decltype({c}{c_member})) calloc(1, sizeof(*{c}{c_member}));\n  \
+                     if (!{c}{c_member}) throw std::bad_alloc();\n  \
                      {conversion}",
-                    ty = member.name_type.ty,
                     c_member = member.name_type.name,
-                    conversion = to_c_conversion_call(&member)
+                    conversion = to_c_conversion_call(member, r, c)
                 )
             }
         } else {
-            to_c_conversion_call(&member)
+            to_c_conversion_call(member, r, c)
         }
     };
     let to_c_members = members
         .iter()
-        .map(|member| to_c_fmt_member(&member))
+        .map(|member| {
+            if let Some(group) = &member.extension_group {
+                let r_group = ros_name(member);
+                format!(
+                    "if (in.{r_group}_is_present) {{\n    \
+                     out.{c_group} = (decltype(out.{c_group})) calloc(1, sizeof(*out.{c_group}));\n    \
+                     if (!out.{c_group}) throw std::bad_alloc();\n    \
+                     {conversions}\n  \
+                     }}",
+                    c_group = group.c_name,
+                    conversions = group
+                        .members
+                        .iter()
+                        .map(|m| to_c_fmt_member(
+                            m,
+                            &format!("in.{r_group}."),
+                            &format!("out.{}->", group.c_name)
+                        )
+                        .replace('\n', "\n  "))
+                        .collect::<Vec<String>>()
+                        .join("\n    "),
+                )
+            } else {
+                to_c_fmt_member(member, "in.", "out.")
+            }
+        })
         .collect::<Vec<String>>()
         .join("\n  ");
 
     let includes = &members
         .iter()
+        .flat_map(|m| match &m.extension_group {
+            Some(group) => group.members.iter().collect(),
+            None => vec![m],
+        })
         .flat_map(|m| {
             if let Some(inners) = &m.name_type.inner_types {
                 match inners {
@@ -494,113 +644,118 @@ pub fn sequence_or_set_template(
 
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &includes,
         name,
         "SEQUENCE",
         &to_ros_members,
         &to_c_members,
+        "",
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn sequence_or_set_of_template(
     options: &ConversionOptions,
-    _is_set_of: bool,
+    is_set_of: bool,
     comments: &str,
     name: &str,
     _anonymous_item: &str,
-    member_type: &str,
+    member: &NameType,
+    element_c_type: &str,
+    constraint_checks: &str,
 ) -> String {
+    let (to_ros_el, to_ros_call) = if member.is_primitive {
+        (
+            "decltype(out.array)::value_type".to_string(),
+            format!("etsi_its_primitives_conversion::toRos_{ty}", ty = member.ty),
+        )
+    } else {
+        (
+            format!(
+                "{pdu}_msgs::{ty}",
+                pdu = &options.main_pdu,
+                ty = options.renames.type_name(&member.ty)
+            ),
+            format!("toRos_{ty}", ty = member.ty),
+        )
+    };
+    let to_c_call = if member.is_primitive {
+        format!("etsi_its_primitives_conversion::toStruct_{ty}", ty = member.ty)
+    } else {
+        format!("toStruct_{ty}", ty = member.ty)
+    };
+    let (add_fn, container) = if is_set_of {
+        ("asn_set_add", "A_SET_OF")
+    } else {
+        ("asn_sequence_add", "A_SEQUENCE_OF")
+    };
+
     let to_ros_loop = format!(
         "for (int i = 0; i < in.list.count; ++i) {{\n    \
-         {pdu}_msgs::{ty} el;\n    \
-         toRos_{ty}(*(in.list.array[i]), el);\n    \
+         {to_ros_el} el;\n    \
+         {to_ros_call}(*(in.list.array[i]), el);\n    \
          out.array.push_back(el);\n  \
-         }}",
-        pdu = &options.main_pdu,
-        ty = member_type
+         }}"
     );
 
+    let to_c_el = with_path(
+        options,
+        format!("{to_c_call}(in.array[i], *el);"),
+        "\"array[\" + std::to_string(i) + \"].\"",
+    )
+    .replace('\n', "\n  ");
     let to_c_loop =
-        format!("for (int i = 0; i < in.array.size(); ++i) {{\n    \
-                 {ty}_t* el = ({ty}_t*) calloc(1, sizeof({ty}_t));\n    \
-                 toStruct_{ty}(in.array[i], *el);\n    \
-                 if (asn_sequence_add(&out, el)) throw std::invalid_argument(\"Failed to add to A_SEQUENCE_OF\");\n  \
-                 }}", 
-                ty = member_type);
+        format!("{constraint_checks}for (int i = 0; i < in.array.size(); ++i) {{\n    \
+                 {element_c_type}* el = ({element_c_type}*) calloc(1, sizeof({element_c_type}));\n    \
+                 if (!el) throw std::bad_alloc();\n    \
+                 if ({add_fn}(&out, el)) {{\n      \
+                 free(el);\n      \
+                 throw std::invalid_argument(\"Failed to add to {container}\");\n    \
+                 }}\n    \
+                 {to_c_el}\n  \
+                 }}");
 
     conversion_template(
         comments,
-        &options.main_pdu,
-        &vec![
-            NameType {
-                name: name.to_string(),
-                ty: name.to_string(),
-                is_primitive: false,
-                inner_types: None,
-            },
-            NameType {
-                name: member_type.to_string(),
-                ty: member_type.to_string(),
-                is_primitive: false,
-                inner_types: None,
-            },
-        ],
+        options,
+        &vec![member.clone()],
         name,
-        "SEQUENCE-OF",
+        if is_set_of { "SET-OF" } else { "SEQUENCE-OF" },
         &to_ros_loop,
         &to_c_loop,
+        "",
     )
 }
 
-pub fn choice_value_template(
-    _comments: &str,
-    _name: &str,
-    _type_id: &str,
-    _choice_name: &str,
-    _inner_decl: &str,
-) -> String {
-    todo!();
-}
-
-pub fn const_choice_value_template(
-    _comments: &str,
-    _name: &str,
-    _type_id: &str,
-    _choice_name: &str,
-    _inner_decl: &str,
-) -> String {
-    todo!();
-}
-
 pub fn choice_template(
     options: &ConversionOptions,
     comments: &str,
     name: &str,
     members: &Vec<NameType>,
 ) -> String {
+    let ros_parent = options.renames.type_name(name);
+    let ros_name = |member: &NameType| options.renames.member_name(name, &member.name).to_string();
+    let primitive_namespace = |member: &NameType| match member.is_primitive {
+        true => "etsi_its_primitives_conversion::",
+        false => "",
+    };
     let to_ros_members = format!("switch (in.present) {{\n")
         + &members
             .iter()
             .map(|member| {
-                if !member.is_primitive {
-                    format!(
-                        "  case {parent}_PR_{c_member}:\n    \
-                         toRos_{ty}(in.choice.{c_member}, out.{r_member});\n    \
-                         out.choice = {pdu}_msgs::{parent}::CHOICE_{r_ch_member};",
-                        parent = &name,
-                        ty = member.ty,
-                        pdu = &options.main_pdu,
-                        c_member = member.name,
-                        r_member = to_ros_snake_case(&member.name),
-                        r_ch_member = to_ros_const_case(&member.name)
-                    )
-                } else {
-                    format!(
-                        "etsi_its_primitives_conversion::toRos_{ty}(in, out.value);",
-                        ty = member.ty,
-                    )
-                }
+                format!(
+                    "  case {parent}_PR_{c_member}:\n    \
+                     {namespace}toRos_{ty}(in.choice.{c_member}, out.{r_member});\n    \
+                     out.choice = {pdu}_msgs::{ros_parent}::CHOICE_{r_ch_member};",
+                    parent = &name,
+                    namespace = primitive_namespace(member),
+                    ty = member.ty,
+                    pdu = &options.main_pdu,
+                    c_member = member.name,
+                    r_member = to_ros_snake_case(&ros_name(member)),
+                    r_ch_member = to_ros_const_case(&ros_name(member))
+                )
             })
             .collect::<Vec<String>>()
             .join("\n    break;\n")
@@ -610,24 +765,40 @@ pub fn choice_template(
         + &members
             .iter()
             .map(|member| {
-                if !member.is_primitive {
+                let r_member = to_ros_snake_case(&ros_name(member));
+                let to_c_call = if member.is_primitive {
+                    let checks = format_member_constraint_checks(
+                        options,
+                        member,
+                        &r_member,
+                        &format!("in.{r_member}"),
+                    );
                     format!(
-                        "  case {pdu}_msgs::{parent}::CHOICE_{r_ch_member}:\n    \
-                         toStruct_{ty}(in.{r_member}, out.choice.{c_member});\n    \
-                         out.present = {parent}_PR::{parent}_PR_{c_member};",
-                        parent = &name,
+                        "{checks}etsi_its_primitives_conversion::toStruct_{ty}(in.{r_member}, out.choice.{c_member});",
                         ty = member.ty,
-                        pdu = &options.main_pdu,
                         c_member = member.name,
-                        r_member = to_ros_snake_case(&member.name),
-                        r_ch_member = to_ros_const_case(&member.name)
                     )
                 } else {
-                    format!(
-                        "etsi_its_primitives_conversion::toStruct_{ty}(in, out.value);",
-                        ty = member.ty,
+                    with_path(
+                        options,
+                        format!(
+                            "toStruct_{ty}(in.{r_member}, out.choice.{c_member});",
+                            ty = member.ty,
+                            c_member = member.name,
+                        ),
+                        &format!("\"{r_member}.\""),
                     )
                 }
+                .replace('\n', "\n  ");
+                format!(
+                    "  case {pdu}_msgs::{ros_parent}::CHOICE_{r_ch_member}:\n    \
+                     {to_c_call}\n    \
+                     out.present = {parent}_PR::{parent}_PR_{c_member};",
+                    parent = &name,
+                    pdu = &options.main_pdu,
+                    c_member = member.name,
+                    r_ch_member = to_ros_const_case(&ros_name(member))
+                )
             })
             .collect::<Vec<String>>()
             .join("\n    break;\n")
@@ -635,11 +806,32 @@ pub fn choice_template(
 
     conversion_template(
         comments,
-        &options.main_pdu,
+        options,
         &members,
         name,
         "CHOICE",
         &to_ros_members,
         &to_c_members,
+        "",
     )
 }
+
+const UMBRELLA_TEMPLATE: &str = r#"//// UMBRELLA {name}
+
+#pragma once
+
+{includes}
+"#;
+
+pub fn umbrella_template(pdu: &str, name: &str, type_names: &[String]) -> String {
+    let includes = type_names
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|ty| format!("#include <etsi_its_{pdu}_conversion/convert{ty}.h>"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    UMBRELLA_TEMPLATE
+        .replace("{name}", name)
+        .replace("{includes}", &includes)
+}
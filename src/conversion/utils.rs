@@ -1,12 +1,13 @@
 use rasn_compiler::intermediate::{
     constraints::Constraint,
     information_object::InformationObjectField,
-    types::{Choice, SequenceOrSet},
+    types::{Choice, SequenceOrSet, SequenceOrSetOf},
     ASN1Type, ASN1Value, CharacterStringType, IntegerType,
 };
 use rasn_compiler::prelude::{ir::*, *};
 
-use crate::common::{to_ros_title_case, IntegerTypeExt};
+use crate::common::{primitive_bounds, range_bounds, to_ros_title_case, RangeBounds};
+use crate::conversion::ConversionOptions;
 
 macro_rules! error {
     ($kind:ident, $($arg:tt)*) => {
@@ -29,6 +30,83 @@ pub fn format_comments(comments: &str) -> Result<String, GeneratorError> {
     }
 }
 
+/// Formats the `toStruct` checks of `value` against the constraint constants of `ros_type`
+pub fn format_constraint_checks(
+    options: &ConversionOptions,
+    ros_type: &str,
+    field: &str,
+    value: &str,
+    signed: bool,
+    constraints: &Vec<Constraint>,
+) -> Result<String, GeneratorError> {
+    if !options.check_constraints {
+        return Ok("".into());
+    }
+    Ok(match range_bounds(signed, constraints)? {
+        Some(bounds) => format_bounds_checks(options, Some(ros_type), field, value, &bounds),
+        None => "".into(),
+    })
+}
+
+/// Formats the `toStruct` checks of the inline primitive `member` at `field`, whose ROS value
+/// is `value`. The messages declare no constants for inline members, so the checks compare
+/// with the bounds themselves.
+pub fn format_member_constraint_checks(
+    options: &ConversionOptions,
+    member: &NameType,
+    field: &str,
+    value: &str,
+) -> String {
+    let value = match member.ty.as_str() {
+        "INTEGER" => value.to_string(),
+        _ => format!("{value}.size()"),
+    };
+    match &member.bounds {
+        Some(bounds) => format_bounds_checks(options, None, field, &value, bounds),
+        None => "".into(),
+    }
+}
+
+fn format_bounds_checks(
+    options: &ConversionOptions,
+    ros_type: Option<&str>,
+    field: &str,
+    value: &str,
+    bounds: &RangeBounds,
+) -> String {
+    // values beyond the root of extensible constraints are encodable
+    if !options.check_constraints || bounds.is_extensible {
+        return "".into();
+    }
+    let prefix = bounds.prefix();
+    let check = |condition: &str, constant: &str, violation: &str, bound: i128| match ros_type {
+        Some(ros_type) => format!(
+            "if ({value} {condition} {pdu}_msgs::{ros_type}::{constant}) \
+             throw std::out_of_range(\"{field}: \" + std::to_string({value}) + \" {violation} {constant} ({bound})\");\n  ",
+            pdu = &options.main_pdu,
+        ),
+        None => format!(
+            "if ({value} {condition} {bound}) \
+             throw std::out_of_range(\"{field}: \" + std::to_string({value}) + \" {violation} {bound}\");\n  ",
+        ),
+    };
+    match (bounds.min, bounds.max) {
+        (Some(min), Some(max)) if min == max => check("!=", prefix, "is not equal to", min),
+        (min, max) => {
+            let mut checks = String::new();
+            // sizes and unsigned values cannot fall below zero
+            let is_unsigned = bounds.is_size || max.is_some();
+            if let Some(min) = min.filter(|min| *min != 0 || !is_unsigned) {
+                checks += &check("<", &format!("{prefix}_MIN"), "is below", min);
+            }
+            if let Some(max) = max {
+                checks += &check(">", &format!("{prefix}_MAX"), "is above", max);
+            }
+            checks
+        }
+    }
+}
+
 pub fn inner_name(name: &String, parent_name: &String) -> String {
     format!("{}{}", parent_name, name)
 }
@@ -39,6 +117,8 @@ pub struct NameType {
     pub ty: String,
     pub is_primitive: bool,
     pub inner_types: Option<InnerTypes>,
+    /// Value or size bounds of inline primitive members, checked in `toStruct`
+    pub bounds: Option<RangeBounds>,
 }
 
 #[derive(Clone, Debug)]
@@ -57,12 +137,52 @@ pub struct NamedSeqMember {
     pub name_type: NameType,
     pub is_optional: bool,
     pub has_default: bool,
+    pub is_extension: bool,
+    pub extension_group: Option<ExtensionGroup>,
 }
 
-fn get_inner_types_names(ty: &ASN1Type) -> Option<InnerTypes> {
+impl NamedSeqMember {
+    /// asn1c declares OPTIONAL, DEFAULT and extension addition members as pointers
+    pub fn is_pointer(&self) -> bool {
+        self.is_optional || self.is_extension
+    }
+
+    /// Whether the ROS message declares a `{member}_is_present` flag
+    pub fn has_present_flag(&self) -> bool {
+        self.is_optional && !self.has_default
+    }
+}
+
+/// Extension addition group, which asn1c nests as an anonymous struct `ext{N}`
+#[derive(Clone, Debug)]
+pub struct ExtensionGroup {
+    pub c_name: String,
+    pub members: Vec<NamedSeqMember>,
+}
+
+/// asn1c declares inline constructed members as anonymous nested structs, and NULL members as
+/// `NULL_t`, neither of which has a conversion function to call
+fn check_inline_member(ty: &ASN1Type, name: &str) -> Result<(), GeneratorError> {
     match ty {
+        ASN1Type::Null
+        | ASN1Type::Enumerated(_)
+        | ASN1Type::Choice(_)
+        | ASN1Type::Sequence(_)
+        | ASN1Type::Set(_)
+        | ASN1Type::SequenceOf(_)
+        | ASN1Type::SetOf(_) => Err(error!(
+            NotYetInplemented,
+            "Inline {} member {name} is currently unsupported!",
+            ty.as_str()
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn get_inner_types_names(ty: &ASN1Type, name: &str) -> Result<Option<InnerTypes>, GeneratorError> {
+    Ok(match ty {
         ASN1Type::InformationObjectFieldReference(r) => {
-            if let Constraint::TableConstraint(ref tc) = r.constraints[0] {
+            if let Some(Constraint::TableConstraint(ref tc)) = r.constraints.first() {
                 let object_set = &tc.object_set;
                 let mut names = vec![];
                 for value in &object_set.values {
@@ -71,76 +191,202 @@ fn get_inner_types_names(ty: &ASN1Type) -> Option<InnerTypes> {
                             InformationObjectFields::DefaultSyntax(ds) => {
                                 let mut name = "".to_string();
                                 let mut ty: ASN1Type = ASN1Type::Null;
-                                ds.iter().for_each(|f| match f {
-                                    InformationObjectField::TypeField(f) => ty = f.ty.clone(),
-                                    InformationObjectField::FixedValueField(f) => {
-                                        name = value_to_tokens(&f.value, None).unwrap()
+                                for f in ds {
+                                    match f {
+                                        InformationObjectField::TypeField(f) => ty = f.ty.clone(),
+                                        InformationObjectField::FixedValueField(f) => {
+                                            name = value_to_tokens(&f.value, None)?
+                                        }
+                                        InformationObjectField::ObjectSetField(_) => {
+                                            return Err(error!(
+                                                NotYetInplemented,
+                                                "Object set fields of information objects are currently unsupported!"
+                                            ))
+                                        }
                                     }
-                                    _ => todo!(),
-                                });
+                                }
                                 names.push(NameType {
                                     name: name.clone(),
-                                    ty: constraints_and_type_name(&ty, &name, &"".to_string())
-                                        .unwrap()
-                                        .1,
+                                    ty: constraints_and_type_name(&ty, &name, &"".to_string())?.1,
                                     is_primitive: ty.is_builtin_type(),
                                     inner_types: None,
+                                    bounds: None,
                                 });
                             }
-                            _ => todo!(),
+                            InformationObjectFields::CustomSyntax(_) => return Err(error!(
+                                NotYetInplemented,
+                                "Information objects in custom syntax are currently unsupported!"
+                            )),
                         }
                     }
                 }
-                let linked_with = tc
-                    .linked_fields
-                    .get(0)
-                    .map(|f| f.field_name.clone())
-                    .unwrap_or_default();
+                let linked_with = match tc.linked_fields.first() {
+                    Some(f) => f.field_name.clone(),
+                    None => {
+                        return Err(error!(
+                            NotYetInplemented,
+                            "Open type member {name} without a component relation is currently unsupported!"
+                        ))
+                    }
+                };
                 Some(InnerTypes::Choice(InnerTypesChoice {
                     linked_with,
                     options: names,
                 }))
             } else {
-                unreachable!()
+                return Err(error!(
+                    NotYetInplemented,
+                    "Information object field references without a table constraint are currently unsupported!"
+                ));
             }
         }
         _ => None,
-    }
+    })
 }
 
-pub fn get_sequence_or_set_members_names(sequence_or_set: &SequenceOrSet) -> Vec<NamedSeqMember> {
-    sequence_or_set
+pub fn get_sequence_or_set_members_names(
+    sequence_or_set: &SequenceOrSet,
+) -> Result<Vec<NamedSeqMember>, GeneratorError> {
+    let first_extension_index = sequence_or_set.extensible.unwrap_or(usize::MAX);
+    let mut extension_groups = 0;
+    let members = sequence_or_set
         .members
         .iter()
-        .map(|member| NamedSeqMember {
-            name_type: NameType {
-                name: member.name.clone(),
-                ty: constraints_and_type_name(&member.ty, &member.name, &"".to_string())
-                    .unwrap()
-                    .1,
-                is_primitive: member.ty.is_builtin_type(),
-                inner_types: get_inner_types_names(&member.ty),
-            },
-            is_optional: member.is_optional,
-            has_default: member.default_value.is_some(),
+        .enumerate()
+        .map(|(i, member)| {
+            let is_extension = i >= first_extension_index;
+            let extension_group = match &member.ty {
+                ASN1Type::Sequence(group) if is_extension && member.name.starts_with("ext_group_") => {
+                    extension_groups += 1;
+                    let members = get_sequence_or_set_members_names(group)?;
+                    if let Some(open) = members.iter().find(|m| m.name_type.inner_types.is_some()) {
+                        return Err(error!(
+                            NotYetInplemented,
+                            "Open type member {} of an extension group is currently unsupported!",
+                            open.name_type.name
+                        ));
+                    }
+                    Some(ExtensionGroup {
+                        c_name: format!("ext{extension_groups}"),
+                        members,
+                    })
+                }
+                _ => {
+                    check_inline_member(&member.ty, &member.name)?;
+                    None
+                }
+            };
+            let (_, ty) = constraints_and_type_name(&member.ty, &member.name, &"".to_string())?;
+            Ok(NamedSeqMember {
+                name_type: NameType {
+                    name: member.name.clone(),
+                    ty,
+                    is_primitive: member.ty.is_builtin_type(),
+                    inner_types: get_inner_types_names(&member.ty, &member.name)?,
+                    bounds: primitive_bounds(&member.ty)?,
+                },
+                is_optional: member.is_optional,
+                has_default: member.default_value.is_some(),
+                is_extension,
+                extension_group,
+            })
         })
-        .collect::<Vec<NamedSeqMember>>()
+        .collect::<Result<Vec<NamedSeqMember>, GeneratorError>>()?;
+    // the open type members are converted along the member their table constraint links to
+    for member in &members {
+        if let Some(InnerTypes::Choice(c)) = &member.name_type.inner_types {
+            if !members.iter().any(|m| m.name_type.name == c.linked_with) {
+                return Err(error!(
+                    NotYetInplemented,
+                    "Open type member {} linked with {} outside the SEQUENCE is currently unsupported!",
+                    member.name_type.name,
+                    c.linked_with
+                ));
+            }
+        }
+    }
+    Ok(members)
 }
 
-pub fn get_choice_members_names(choice: &Choice) -> Vec<NameType> {
+pub fn get_choice_members_names(choice: &Choice) -> Result<Vec<NameType>, GeneratorError> {
     choice
         .options
         .iter()
-        .map(|member| 
-            NameType {
+        .map(|member| {
+            check_inline_member(&member.ty, &member.name)?;
+            let (_, ty) = constraints_and_type_name(&member.ty, &member.name, &"".to_string())?;
+            Ok(NameType {
                 name: member.name.clone(),
-                ty: constraints_and_type_name(&member.ty, &member.name, &"".to_string())
-                    .unwrap()
-                    .1,
+                ty,
                 is_primitive: member.ty.is_builtin_type(),
                 inner_types: None,
+                bounds: primitive_bounds(&member.ty)?,
+            })
+        })
+        .collect::<Result<Vec<NameType>, _>>()
+}
+
+pub fn get_sequence_or_set_of_member_name_type(
+    sequence_or_set_of: &SequenceOrSetOf,
+    parent_name: &String,
+) -> Result<NameType, GeneratorError> {
+    Ok(match sequence_or_set_of.element_type.as_ref() {
+        ASN1Type::ElsewhereDeclaredType(d) => NameType {
+            name: d.identifier.clone(),
+            ty: d.identifier.clone(),
+            is_primitive: false,
+            inner_types: None,
+            bounds: None,
+        },
+        ty @ (ASN1Type::Boolean(_) | ASN1Type::Integer(_) | ASN1Type::CharacterString(_)) => {
+            NameType {
+                name: parent_name.clone(),
+                ty: constraints_and_type_name(ty, parent_name, &"".to_string())?.1,
+                is_primitive: true,
+                inner_types: None,
+                bounds: None,
             }
-        ).collect::<Vec<NameType>>()
+        }
+        // the ROS messages have no nested arrays
+        ty @ (ASN1Type::BitString(_) | ASN1Type::OctetString(_)) => {
+            return Err(error!(
+                NotYetInplemented,
+                "{} elements are currently unsupported!",
+                ty.as_str()
+            ))
+        }
+        _ => NameType {
+            name: parent_name.clone(),
+            ty: format!("Anonymous{}", parent_name),
+            is_primitive: false,
+            inner_types: None,
+            bounds: None,
+        },
+    })
+}
+
+/// asn1c type of the elements of a SEQUENCE or SET OF. Like asn1c, INTEGERs constrained to 32
+/// bits are native `long`s, or `unsigned long`s if non-negative, and other INTEGERs `INTEGER_t`s.
+pub fn element_c_type(
+    sequence_or_set_of: &SequenceOrSetOf,
+    member: &NameType,
+) -> Result<String, GeneratorError> {
+    Ok(match sequence_or_set_of.element_type.as_ref() {
+        ASN1Type::Integer(i) => match range_bounds(true, &i.constraints)? {
+            Some(RangeBounds {
+                min: Some(min),
+                max: Some(max),
+                ..
+            }) if min >= i32::MIN.into() && max <= i32::MAX.into() => "long".into(),
+            Some(RangeBounds {
+                min: Some(min),
+                max: Some(max),
+                ..
+            }) if min >= 0 && max <= u32::MAX.into() => "unsigned long".into(),
+            _ => "INTEGER_t".into(),
+        },
+        _ => format!("{}_t", member.ty),
+    })
 }
 
 fn constraints_and_type_name(
@@ -153,12 +399,19 @@ fn constraints_and_type_name(
         ASN1Type::Boolean(b) => (b.constraints.clone(), "BOOLEAN".into()),
         ASN1Type::Integer(i) => (i.constraints.clone(), "INTEGER".into()),
         ASN1Type::Real(_) => (vec![], "float64".into()),
-        ASN1Type::ObjectIdentifier(_o) => todo!(),
-        ASN1Type::BitString(_b) => todo!(),
-        ASN1Type::OctetString(o) => (o.constraints.clone(), "uint8[]".into()),
-        ASN1Type::GeneralizedTime(_o) => todo!(),
-        ASN1Type::UTCTime(_o) => todo!(),
-        ASN1Type::Time(_t) => todo!(),
+        ASN1Type::OctetString(o) => (o.constraints.clone(), "OCTET_STRING".into()),
+        // the messages have no `bits_unused` field for inline BIT STRINGs
+        ASN1Type::ObjectIdentifier(_)
+        | ASN1Type::BitString(_)
+        | ASN1Type::GeneralizedTime(_)
+        | ASN1Type::UTCTime(_)
+        | ASN1Type::Time(_) => {
+            return Err(error!(
+                NotYetInplemented,
+                "Inline {} member {name} is currently unsupported!",
+                ty.as_str()
+            ))
+        }
         ASN1Type::CharacterString(c) => (
             c.constraints.clone(),
             string_type(&c.ty).unwrap_or("STRING".into()),
@@ -177,23 +430,35 @@ fn constraints_and_type_name(
         }
         ASN1Type::InformationObjectFieldReference(_)
         | ASN1Type::EmbeddedPdv
-        | ASN1Type::External => {
-            let tx = &ty.constraints().unwrap()[0];
-            let rname = if let Constraint::TableConstraint(ref tc) = tx {
-                tc.object_set
+        | ASN1Type::External => match ty.constraints().and_then(|c| c.first()) {
+            Some(Constraint::TableConstraint(tc)) => {
+                let rname = tc
+                    .object_set
                     .values
                     .iter()
                     .find_map(|v| match v {
                         ObjectSetValue::Reference(ref r) => Some(r.clone()),
                         _ => None,
                     })
-                    .unwrap_or_default()
-            } else {
-                "".to_string()
-            };
-            (vec![], rname)
+                    .unwrap_or_default();
+                (vec![], rname)
+            }
+            _ => {
+                return Err(error!(
+                    NotYetInplemented,
+                    "Inline {} member {name} is currently unsupported!",
+                    ty.as_str()
+                ))
+            }
+        },
+        ASN1Type::ChoiceSelectionType(c) => {
+            return Err(error!(
+                NotYetInplemented,
+                "Choice selection type {}<{} member {name} is currently unsupported!",
+                c.selected_option,
+                c.choice_name
+            ))
         }
-        ASN1Type::ChoiceSelectionType(_) => unreachable!(),
     })
 }
 
@@ -222,10 +487,16 @@ pub fn value_to_tokens(
             NotYetInplemented,
             "All values are currently unsupported!"
         )),
-        ASN1Value::Null => todo!(),
+        ASN1Value::Null => Err(error!(
+            NotYetInplemented,
+            "Null values are currently unsupported!"
+        )),
         ASN1Value::Choice { inner_value, .. } => {
-            if let Some(_ty_n) = type_name {
-                todo!()
+            if let Some(ty_n) = type_name {
+                Err(error!(
+                    NotYetInplemented,
+                    "Choice values of {ty_n} are currently unsupported!"
+                ))
             } else {
                 Err(error!(
                     Unidentified,
@@ -233,21 +504,20 @@ pub fn value_to_tokens(
                 ))
             }
         }
-        ASN1Value::OctetString(o) => {
-            let _bytes = o.iter().map(|byte| *byte);
-            todo!()
-        }
+        ASN1Value::OctetString(_) => Err(error!(
+            NotYetInplemented,
+            "Octet String values are currently unsupported!"
+        )),
         ASN1Value::SequenceOrSet(_) => Err(error!(
             Unidentified,
             "Unexpectedly encountered unlinked struct-like ASN1 value!"
         )),
-        ASN1Value::LinkedStructLikeValue(fields) => {
-            if let Some(_ty_n) = type_name {
-                let _tokenized_fields = fields
-                    .iter()
-                    .map(|(_, _, val)| value_to_tokens(val.value(), None))
-                    .collect::<Result<Vec<String>, _>>()?;
-                todo!()
+        ASN1Value::LinkedStructLikeValue(_) => {
+            if let Some(ty_n) = type_name {
+                Err(error!(
+                    NotYetInplemented,
+                    "Sequence values of {ty_n} are currently unsupported!"
+                ))
             } else {
                 Err(error!(
                     Unidentified,
@@ -259,34 +529,28 @@ pub fn value_to_tokens(
         ASN1Value::Integer(i) => Ok(i.to_string()),
         ASN1Value::String(s) => Ok(s.to_string()),
         ASN1Value::Real(r) => Ok(r.to_string()),
-        ASN1Value::BitString(b) => {
-            let _bits = b.iter().map(|bit| bit.to_string());
-            todo!()
-        }
+        ASN1Value::BitString(_) => Err(error!(
+            NotYetInplemented,
+            "Bit String values are currently unsupported!"
+        )),
         ASN1Value::EnumeratedValue {
             enumerated,
             enumerable,
         } => Ok(format!("{}_{}", enumerated, enumerable)),
         ASN1Value::LinkedElsewhereDefinedValue { identifier: e, .. }
         | ASN1Value::ElsewhereDeclaredValue { identifier: e, .. } => Ok(e.to_string()),
-        ASN1Value::ObjectIdentifier(oid) => {
-            let _arcs = oid
-                .0
-                .iter()
-                .filter_map(|arc| arc.number.map(|id| id.to_string()));
-            todo!()
-        }
-        ASN1Value::Time(_t) => match type_name {
-            Some(_time_type) => todo!(),
-            None => todo!(),
-        },
-        ASN1Value::LinkedArrayLikeValue(seq) => {
-            let _elems = seq
-                .iter()
-                .map(|v| value_to_tokens(v, None))
-                .collect::<Result<Vec<_>, _>>()?;
-            todo!()
-        }
+        ASN1Value::ObjectIdentifier(_) => Err(error!(
+            NotYetInplemented,
+            "Object Identifier values are currently unsupported!"
+        )),
+        ASN1Value::Time(_) => Err(error!(
+            NotYetInplemented,
+            "Time values are currently unsupported!"
+        )),
+        ASN1Value::LinkedArrayLikeValue(_) => Err(error!(
+            NotYetInplemented,
+            "Sequence Of values are currently unsupported!"
+        )),
         ASN1Value::LinkedNestedValue {
             supertypes: _,
             value,
@@ -301,69 +565,10 @@ pub fn value_to_tokens(
                 _ => Ok(val.to_string()),
             }
         }
-        ASN1Value::LinkedCharStringValue(string_type, value) => {
-            let _val = value;
-            match string_type {
-                CharacterStringType::NumericString => {
-                    todo!()
-                }
-                CharacterStringType::VisibleString => {
-                    todo!()
-                }
-                CharacterStringType::IA5String => {
-                    todo!()
-                }
-                CharacterStringType::UTF8String => todo!(),
-                CharacterStringType::BMPString => {
-                    todo!()
-                }
-                CharacterStringType::PrintableString => {
-                    todo!()
-                }
-                CharacterStringType::GeneralString => {
-                    todo!()
-                }
-                CharacterStringType::VideotexString
-                | CharacterStringType::GraphicString
-                | CharacterStringType::UniversalString
-                | CharacterStringType::TeletexString => Err(GeneratorError::new(
-                    None,
-                    &format!("{:?} values are currently unsupported!", string_type),
-                    GeneratorErrorType::NotYetInplemented,
-                )),
-            }
-        }
-    }
-}
-
-pub fn format_sequence_or_set_of_item_type(
-    type_name: String,
-    first_item: Option<&ASN1Value>,
-) -> String {
-    match type_name {
-        name if name == NULL => todo!(),
-        name if name == BOOLEAN => "bool".into(),
-        name if name == INTEGER => {
-            match first_item {
-                Some(ASN1Value::LinkedIntValue { integer_type, .. }) => {
-                    integer_type.to_str().into()
-                }
-                _ => "int64".into(), // best effort
-            }
-        }
-        name if name == BIT_STRING => "BitString".into(),
-        name if name == OCTET_STRING => "OctetString".into(),
-        name if name == GENERALIZED_TIME => "GeneralizedTime".into(),
-        name if name == UTC_TIME => "UtcTime".into(),
-        name if name == OBJECT_IDENTIFIER => "ObjectIdentifier".into(),
-        name if name == NUMERIC_STRING => "NumericString".into(),
-        name if name == VISIBLE_STRING => "VisibleString".into(),
-        name if name == IA5_STRING => "IA5String".into(),
-        name if name == UTF8_STRING => "UTF8String".into(),
-        name if name == BMP_STRING => "BMPString".into(),
-        name if name == PRINTABLE_STRING => "PrintableString".into(),
-        name if name == GENERAL_STRING => "GeneralString".into(),
-        name => name,
+        ASN1Value::LinkedCharStringValue(string_type, _) => Err(error!(
+            NotYetInplemented,
+            "{:?} values are currently unsupported!", string_type
+        )),
     }
 }
 
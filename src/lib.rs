@@ -1,3 +1,12 @@
+// The generators return rasn-compiler's `GeneratorError`, whose size is not ours to choose
+#![allow(clippy::result_large_err)]
+
+pub mod cli;
 mod common;
 pub mod conversion;
 pub mod msgs;
+pub mod package;
+pub mod python_conversion;
+pub mod renames;
+pub mod rust_conversion;
+pub mod validation;
@@ -14,7 +14,8 @@ impl Backend for Msgs {
         &self,
         tlds: Vec<ToplevelDefinition>,
     ) -> Result<GeneratedModule, GeneratorError> {
-        let tlds = merge_tlds(tlds);
+        let renames = self.renames.in_module(&tlds);
+        let tlds = merge_tlds(tlds).into_iter().map(|tld| renames.apply(tld));
         let (pdus, warnings): (Vec<String>, Vec<Box<dyn Error>>) =
             tlds.into_iter()
                 .fold((vec![], vec![]), |mut acc, tld| match generate(tld) {
@@ -156,6 +157,7 @@ pub fn generate_integer(tld: ToplevelTypeDefinition) -> Result<String, Generator
             &format_constraints(true, &int.constraints)?,
             int.int_type().to_str(),
             &format_distinguished_values(&int.distinguished_values),
+            &format_unit_scale(&tld.comments, &int.distinguished_values),
         ))
     } else {
         Err(GeneratorError::new(
@@ -252,108 +254,49 @@ macro_rules! assignment {
 
 pub fn generate_value(tld: ToplevelValueDefinition) -> Result<String, GeneratorError> {
     let ty = tld.associated_type.as_str();
+    let unsupported = || {
+        Err(error!(
+            NotYetInplemented,
+            "{ty} values are currently unsupported!"
+        ))
+    };
     match &tld.value {
-        ASN1Value::Null if ty == NULL => {
+        ASN1Value::LinkedIntValue { .. } => generate_integer_value(tld),
+        ASN1Value::LinkedNestedValue { supertypes, value } if !value.is_const_type() => {
+            let parent = supertypes.last();
             call_template!(
-                primitive_value_template,
+                lazy_static_value_template,
                 tld,
-                "quote!(())".into(),
-                "quote!(())".into()
+                &tld.associated_type,
+                assignment!(&tld.associated_type, value_to_tokens(&tld.value, parent)?)
             )
         }
-        ASN1Value::Null => call_template!(
-            primitive_value_template,
-            tld,
-            &tld.associated_type,
-            assignment!(&tld.associated_type, "byte null 0".to_string())
-        ),
-        ASN1Value::Boolean(b) if ty == BOOLEAN => {
-            call_template!(primitive_value_template, tld, "bool", &b.to_string())
-        }
-        ASN1Value::Boolean(b) => call_template!(
-            primitive_value_template,
-            tld,
-            &tld.associated_type,
-            assignment!(&tld.associated_type, b.to_string())
-        ),
-        ASN1Value::LinkedIntValue { .. } => generate_integer_value(tld),
-        ASN1Value::BitString(_) if ty == BIT_STRING => todo!(),
-        ASN1Value::OctetString(_) if ty == OCTET_STRING => todo!(),
-        ASN1Value::Choice {
-            variant_name,
-            inner_value,
-            ..
-        } => {
-            if inner_value.is_const_type() {
-                call_template!(
-                    const_choice_value_template,
-                    tld,
-                    &tld.associated_type,
-                    variant_name,
-                    &value_to_tokens(inner_value, None)?
-                )
-            } else {
-                call_template!(
-                    choice_value_template,
-                    tld,
-                    &tld.associated_type,
-                    &variant_name,
-                    &value_to_tokens(inner_value, None)?
-                )
-            }
-        }
-        ASN1Value::EnumeratedValue {
-            enumerated,
-            enumerable,
-        } => call_template!(enum_value_template, tld, enumerated, enumerable),
-        ASN1Value::Time(_) if ty == GENERALIZED_TIME => todo!(),
-        ASN1Value::Time(_) if ty == UTC_TIME => todo!(),
-        ASN1Value::LinkedStructLikeValue(s) => {
-            let _members = s
-                .iter()
-                .map(|(_, _, val)| value_to_tokens(val.value(), None))
-                .collect::<Result<Vec<String>, _>>()?;
-            todo!()
-        }
-        ASN1Value::LinkedNestedValue { supertypes, value } => {
-            let parent = supertypes.last().map(|s| (s));
-            if value.is_const_type() {
-                call_template!(
-                    primitive_value_template,
-                    tld,
-                    &tld.associated_type,
-                    assignment!(&tld.associated_type, value_to_tokens(&tld.value, parent)?)
-                )
-            } else {
-                call_template!(
-                    lazy_static_value_template,
-                    tld,
-                    &tld.associated_type,
-                    assignment!(&tld.associated_type, value_to_tokens(&tld.value, parent)?)
-                )
-            }
-        }
-        ASN1Value::ObjectIdentifier(_) if ty == OBJECT_IDENTIFIER => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == NUMERIC_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == VISIBLE_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == IA5_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == UTF8_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == BMP_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == PRINTABLE_STRING => todo!(),
-        ASN1Value::LinkedCharStringValue(_, _) if ty == GENERAL_STRING => todo!(),
-        ASN1Value::LinkedArrayLikeValue(s) if ty.contains(SEQUENCE_OF) => {
-            let _item_type = format_sequence_or_set_of_item_type(
-                ty.replace(SEQUENCE_OF, "").trim().to_string(),
-                s.first().map(|i| &**i),
-            );
-            todo!()
+        ASN1Value::Null
+        | ASN1Value::Boolean(_)
+        | ASN1Value::Choice { .. }
+        | ASN1Value::EnumeratedValue { .. }
+        | ASN1Value::LinkedStructLikeValue(_)
+        | ASN1Value::LinkedNestedValue { .. } => unsupported(),
+        ASN1Value::BitString(_) if ty == BIT_STRING => unsupported(),
+        ASN1Value::OctetString(_) if ty == OCTET_STRING => unsupported(),
+        ASN1Value::Time(_) if ty == GENERALIZED_TIME || ty == UTC_TIME => unsupported(),
+        ASN1Value::ObjectIdentifier(_) if ty == OBJECT_IDENTIFIER => unsupported(),
+        ASN1Value::LinkedCharStringValue(_, _)
+            if [
+                NUMERIC_STRING,
+                VISIBLE_STRING,
+                IA5_STRING,
+                UTF8_STRING,
+                BMP_STRING,
+                PRINTABLE_STRING,
+                GENERAL_STRING,
+            ]
+            .contains(&ty) =>
+        {
+            unsupported()
         }
-        ASN1Value::LinkedArrayLikeValue(s) if ty.contains(SET_OF) => {
-            let _item_type = format_sequence_or_set_of_item_type(
-                ty.replace(SET_OF, "").trim().to_string(),
-                s.first().map(|i| &**i),
-            );
-            todo!()
+        ASN1Value::LinkedArrayLikeValue(_) if ty.contains(SEQUENCE_OF) || ty.contains(SET_OF) => {
+            unsupported()
         }
         ASN1Value::BitString(_)
         | ASN1Value::Time(_)
@@ -372,19 +315,18 @@ pub fn generate_value(tld: ToplevelValueDefinition) -> Result<String, GeneratorE
 }
 
 pub fn generate_any(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
-    Ok(any_template(
-        &format_comments(&tld.comments)?,
-        &tld.name,
-        "",
+    Err(error!(
+        NotYetInplemented,
+        "{} types are currently unsupported!",
+        tld.ty.as_str()
     ))
 }
 
 pub fn generate_generalized_time(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::GeneralizedTime(_) = &tld.ty {
-        Ok(generalized_time_template(
-            &format_comments(&tld.comments)?,
-            &tld.name,
-            "",
+        Err(error!(
+            NotYetInplemented,
+            "GeneralizedTime types are currently unsupported!"
         ))
     } else {
         Err(GeneratorError::new(
@@ -397,10 +339,9 @@ pub fn generate_generalized_time(tld: ToplevelTypeDefinition) -> Result<String,
 
 pub fn generate_utc_time(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::UTCTime(_) = &tld.ty {
-        Ok(utc_time_template(
-            &format_comments(&tld.comments)?,
-            &tld.name,
-            "",
+        Err(error!(
+            NotYetInplemented,
+            "UTCTime types are currently unsupported!"
         ))
     } else {
         Err(GeneratorError::new(
@@ -413,10 +354,9 @@ pub fn generate_utc_time(tld: ToplevelTypeDefinition) -> Result<String, Generato
 
 pub fn generate_oid(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::ObjectIdentifier(_oid) = &tld.ty {
-        Ok(oid_template(
-            &format_comments(&tld.comments)?,
-            &tld.name,
-            "",
+        Err(error!(
+            NotYetInplemented,
+            "OBJECT IDENTIFIER types are currently unsupported!"
         ))
     } else {
         Err(GeneratorError::new(
@@ -429,10 +369,9 @@ pub fn generate_oid(tld: ToplevelTypeDefinition) -> Result<String, GeneratorErro
 
 pub fn generate_null(tld: ToplevelTypeDefinition) -> Result<String, GeneratorError> {
     if let ASN1Type::Null = tld.ty {
-        Ok(null_template(
-            &format_comments(&tld.comments)?,
-            &tld.name,
-            "",
+        Err(error!(
+            NotYetInplemented,
+            "NULL types are currently unsupported!"
         ))
     } else {
         Err(GeneratorError::new(
@@ -528,8 +467,10 @@ pub fn generate_sequence_or_set_of(tld: ToplevelTypeDefinition) -> Result<String
             ))
         }
     };
+    let primitive_type = primitive_element_type(&seq_or_set_of.element_type)?;
     let anonymous_item = match seq_or_set_of.element_type.as_ref() {
         ASN1Type::ElsewhereDeclaredType(_) => None,
+        _ if primitive_type.is_some() => None,
         n => Some(generate(ToplevelDefinition::Type(
             ToplevelTypeDefinition {
                 parameterization: None,
@@ -545,8 +486,9 @@ pub fn generate_sequence_or_set_of(tld: ToplevelTypeDefinition) -> Result<String
         ))?),
     }
     .unwrap_or_default();
-    let member_type = match seq_or_set_of.element_type.as_ref() {
-        ASN1Type::ElsewhereDeclaredType(d) => d.identifier.clone(),
+    let member_type = match (seq_or_set_of.element_type.as_ref(), primitive_type) {
+        (ASN1Type::ElsewhereDeclaredType(d), _) => d.identifier.clone(),
+        (_, Some(primitive_type)) => primitive_type,
         _ => format!("Anonymous{}", &tld.name),
     };
     let constraints = format_constraints(true, &seq_or_set_of.constraints)?;
@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::msgs::Msgs;
+use crate::package::BuildSystem;
+
+const MANIFEST_TEMPLATE: &str = r#"# Message files in dependency order, {usage}
+set(ROS_MSG_FILES
+{files}
+)
+"#;
+
+const AMENT_USAGE: &str = r#"for use with
+#   include(msg/msg_files.cmake)
+#   rosidl_generate_interfaces(${PROJECT_NAME} ${ROS_MSG_FILES})"#;
+
+const CATKIN_USAGE: &str = r#"for use with
+#   include(msg/msg_files.cmake)
+#   add_message_files(DIRECTORY msg FILES ${ROS_MSG_FILES})"#;
+
+const FLAT_USAGE: &str = "relative to the directory of this file";
+
+pub(super) const BUILTIN_TYPES: [&str; 17] = [
+    "bool", "byte", "char", "float32", "float64", "int8", "uint8", "int16", "uint16", "int32",
+    "uint32", "int64", "uint64", "string", "wstring", "time", "duration",
+];
+
+/// Message types referenced by the fields of a `.msg` definition
+fn referenced_types(definition: &str) -> BTreeSet<String> {
+    definition
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_whitespace().next())
+        .map(|ty| ty.split('[').next().unwrap_or(ty))
+        .map(|ty| ty.rsplit('/').next().unwrap_or(ty))
+        .filter(|ty| !BUILTIN_TYPES.contains(ty) && !ty.starts_with("string<="))
+        .map(String::from)
+        .collect()
+}
+
+/// Orders message names so that every message comes after the messages it references
+pub fn dependency_order(messages: &[(String, String)]) -> Vec<String> {
+    let names: BTreeSet<&String> = messages.iter().map(|(name, _)| name).collect();
+    let mut pending: BTreeMap<&String, BTreeSet<String>> = messages
+        .iter()
+        .map(|(name, definition)| {
+            let deps = referenced_types(definition)
+                .into_iter()
+                .filter(|dep| dep != name && names.contains(dep))
+                .collect();
+            (name, deps)
+        })
+        .collect();
+
+    let mut ordered = vec![];
+    while !pending.is_empty() {
+        let ready: Vec<&String> = pending
+            .iter()
+            .filter(|(_, deps)| deps.is_empty())
+            .map(|(name, _)| *name)
+            .collect();
+        // Cyclic references cannot be resolved, keep the remaining messages in name order
+        let ready = if ready.is_empty() {
+            pending.keys().copied().collect()
+        } else {
+            ready
+        };
+        for name in ready {
+            pending.remove(name);
+            pending.values_mut().for_each(|deps| {
+                deps.remove(name);
+            });
+            ordered.push(name.clone());
+        }
+    }
+    ordered
+}
+
+impl Msgs {
+    /// Generates the CMake manifest `msg_files.cmake`, as a `(file name, content)` pair,
+    /// listing the given `(name, definition)` messages in dependency order. The paths and the
+    /// usage comment follow the layout of the `build` package, or are relative to the manifest
+    /// for the flat layout without a package.
+    pub fn generate_manifest(
+        &self,
+        messages: &[(String, String)],
+        build: Option<BuildSystem>,
+    ) -> (String, String) {
+        let (usage, prefix) = match build {
+            Some(BuildSystem::Ament) => (AMENT_USAGE, "msg/"),
+            Some(BuildSystem::Catkin) => (CATKIN_USAGE, ""),
+            None => (FLAT_USAGE, ""),
+        };
+        let files = dependency_order(messages)
+            .iter()
+            .map(|name| format!("  {prefix}{name}.msg"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        (
+            "msg_files.cmake".to_string(),
+            MANIFEST_TEMPLATE
+                .replace("{usage}", usage)
+                .replace("{files}", &files),
+        )
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use md5::{Digest, Md5};
+
+use super::manifest::BUILTIN_TYPES;
+use crate::msgs::Msgs;
+
+/// Text that ROS 1 hashes into the MD5 sum of a message: the constants, followed by the fields
+/// with the MD5 sums of their message types in place of the type names
+fn md5_text(
+    package: &str,
+    definition: &str,
+    md5sum: &mut impl FnMut(&str) -> Result<String, Box<dyn Error>>,
+) -> Result<String, Box<dyn Error>> {
+    let mut constants = vec![];
+    let mut fields = vec![];
+    for raw_line in definition.lines() {
+        let line = raw_line.split('#').next().unwrap_or_default().trim();
+        let Some((ty, rest)) = line.split_once(char::is_whitespace) else {
+            continue;
+        };
+        if let Some((name, value)) = rest.split_once('=') {
+            // string constants keep everything after the `=`, including `#`
+            let value = match ty {
+                "string" => raw_line.split_once('=').map_or("", |(_, value)| value),
+                _ => value,
+            };
+            constants.push(format!("{ty} {}={}", name.trim(), value.trim()));
+            continue;
+        }
+        let name = rest.trim();
+        let base_type = ty.split('[').next().unwrap_or(ty);
+        if BUILTIN_TYPES.contains(&base_type) {
+            fields.push(format!("{ty} {name}"));
+            continue;
+        }
+        let message = match base_type.split_once('/') {
+            Some((type_package, message)) if type_package == package => message,
+            Some(_) => return Err(format!("{base_type} is not a message of {package}").into()),
+            None => base_type,
+        };
+        fields.push(format!("{} {name}", md5sum(message)?));
+    }
+    Ok(constants
+        .into_iter()
+        .chain(fields)
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+impl Msgs {
+    /// Generates the manifest `md5sums.txt`, as a `(file name, content)` pair, listing the
+    /// ROS 1 MD5 sum of each of the given `(name, definition)` messages of `package`, as
+    /// `rosmsg md5` prints it
+    pub fn generate_md5sums(
+        &self,
+        package: &str,
+        messages: &[(String, String)],
+    ) -> Result<(String, String), Box<dyn Error>> {
+        let definitions: BTreeMap<&str, &str> = messages
+            .iter()
+            .map(|(name, definition)| (name.as_str(), definition.as_str()))
+            .collect();
+
+        fn md5sum(
+            name: &str,
+            package: &str,
+            definitions: &BTreeMap<&str, &str>,
+            md5sums: &mut BTreeMap<String, Option<String>>,
+        ) -> Result<String, Box<dyn Error>> {
+            match md5sums.get(name) {
+                Some(Some(md5sum)) => return Ok(md5sum.clone()),
+                Some(None) => return Err(format!("{package}/{name} references itself").into()),
+                None => {}
+            }
+            let definition = definitions
+                .get(name)
+                .ok_or_else(|| format!("{package}/{name} is not a generated message"))?;
+            md5sums.insert(name.to_string(), None);
+            let text = md5_text(package, definition, &mut |nested| {
+                md5sum(nested, package, definitions, md5sums)
+            })
+            .map_err(|e| format!("{package}/{name}: {e}"))?;
+            let md5sum = format!("{:x}", Md5::digest(text));
+            md5sums.insert(name.to_string(), Some(md5sum.clone()));
+            Ok(md5sum)
+        }
+
+        let mut md5sums = BTreeMap::new();
+        let mut lines = String::new();
+        for name in definitions.keys() {
+            let md5sum = md5sum(name, package, &definitions, &mut md5sums)?;
+            lines += &format!("{package}/{name} {md5sum}\n");
+        }
+        Ok(("md5sums.txt".to_string(), lines))
+    }
+}
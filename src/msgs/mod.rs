@@ -2,14 +2,39 @@ use rasn_compiler::prelude::ir::{ASN1Information, ASN1Type};
 use rasn_compiler::prelude::*;
 
 mod builder;
+mod manifest;
+mod md5sums;
 mod template;
+mod type_description;
 mod utils;
 
-pub struct Msgs;
+use crate::renames::Renames;
+
+#[derive(Default, Clone)]
+pub struct Msgs {
+    renames: Renames,
+}
+
+impl Msgs {
+    /// Rename ROS messages and fields
+    pub fn set_renames(mut self, renames: Renames) -> Self {
+        self.renames = renames;
+        self
+    }
+}
 
 use builder::*;
+pub use manifest::dependency_order;
 
 fn generate(tld: ToplevelDefinition) -> Result<String, GeneratorError> {
+    // report unsupported constructs with the name of the offending type
+    generate_definition(tld.clone()).map_err(|mut e| {
+        e.top_level_declaration.get_or_insert(tld);
+        e
+    })
+}
+
+fn generate_definition(tld: ToplevelDefinition) -> Result<String, GeneratorError> {
     match tld {
         ToplevelDefinition::Type(t) => {
             if t.parameterization.is_some() {
@@ -27,7 +52,11 @@ fn generate(tld: ToplevelDefinition) -> Result<String, GeneratorError> {
                 ASN1Type::ElsewhereDeclaredType(_) => generate_typealias(t),
                 ASN1Type::Choice(_) => generate_choice(t),
                 ASN1Type::OctetString(_) => generate_octet_string(t),
-                ASN1Type::Time(_) => unimplemented!("rasn does not support TIME types yet!"),
+                ASN1Type::Time(_) => Err(GeneratorError {
+                    kind: GeneratorErrorType::NotYetInplemented,
+                    details: "Time types are currently unsupported!".into(),
+                    top_level_declaration: None,
+                }),
                 ASN1Type::Real(_) => Err(GeneratorError {
                     kind: GeneratorErrorType::NotYetInplemented,
                     details: "Real types are currently unsupported!".into(),
@@ -39,7 +68,11 @@ fn generate(tld: ToplevelDefinition) -> Result<String, GeneratorError> {
                 | ASN1Type::External => generate_any(t),
                 ASN1Type::GeneralizedTime(_) => generate_generalized_time(t),
                 ASN1Type::UTCTime(_) => generate_utc_time(t),
-                ASN1Type::ChoiceSelectionType(_) => unreachable!(),
+                ASN1Type::ChoiceSelectionType(_) => Err(GeneratorError {
+                    kind: GeneratorErrorType::NotYetInplemented,
+                    details: "Choice selection types are currently unsupported!".into(),
+                    top_level_declaration: None,
+                }),
             }
         }
         ToplevelDefinition::Value(v) => generate_value(v),
@@ -29,6 +29,7 @@ pub fn integer_template(
     constraints: &str,
     integer_type: &str,
     dvalues: &str,
+    unit_scale: &str,
 ) -> String {
     let typed_dvalues = dvalues
         .replace("{type}", &integer_type)
@@ -38,18 +39,11 @@ pub fn integer_template(
         {comments}\n\
         {integer_type} value\n\n\
         {constraints}\n\n\
-        {typed_dvalues}"
+        {typed_dvalues}\
+        {unit_scale}"
     )
 }
 
-pub fn generalized_time_template(_comments: &str, _name: &str, _annotations: &str) -> String {
-    todo!();
-}
-
-pub fn utc_time_template(_comments: &str, _name: &str, _annotations: &str) -> String {
-    todo!();
-}
-
 pub fn bit_string_template(comments: &str, name: &str, constraints: &str, dvalues: &str) -> String {
     let typed_dvalues = dvalues
         .replace("{type}", "uint8")
@@ -96,36 +90,6 @@ pub fn boolean_template(comments: &str, name: &str, annotations: &str) -> String
     )
 }
 
-pub fn primitive_value_template(
-    _comments: &str,
-    _name: &str,
-    _type_name: &str,
-    _assignment: &str,
-) -> String {
-    todo!();
-}
-
-pub fn enum_value_template(
-    _comments: &str,
-    _name: &str,
-    _enumerated: &str,
-    _enumeral: &str,
-) -> String {
-    todo!();
-}
-
-pub fn null_template(_comments: &str, _name: &str, _annotations: &str) -> String {
-    todo!();
-}
-
-pub fn any_template(_comments: &str, _name: &str, _annotations: &str) -> String {
-    todo!();
-}
-
-pub fn oid_template(_comments: &str, _name: &str, _annotations: &str) -> String {
-    todo!();
-}
-
 pub fn enumerated_template(
     comments: &str,
     name: &str,
@@ -142,15 +106,6 @@ pub fn enumerated_template(
     )
 }
 
-pub fn _sequence_or_set_value_template(
-    _comments: &str,
-    _name: &str,
-    _vtype: &str,
-    _members: &str,
-) -> String {
-    todo!()
-}
-
 #[allow(clippy::too_many_arguments)]
 pub fn sequence_or_set_template(
     comments: &str,
@@ -190,26 +145,6 @@ pub fn sequence_or_set_of_template(
     )
 }
 
-pub fn choice_value_template(
-    _comments: &str,
-    _name: &str,
-    _type_id: &str,
-    _choice_name: &str,
-    _inner_decl: &str,
-) -> String {
-    todo!();
-}
-
-pub fn const_choice_value_template(
-    _comments: &str,
-    _name: &str,
-    _type_id: &str,
-    _choice_name: &str,
-    _inner_decl: &str,
-) -> String {
-    todo!();
-}
-
 pub fn choice_template(
     comments: &str,
     name: &str,
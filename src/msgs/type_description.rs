@@ -0,0 +1,291 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+
+use serde::Serialize;
+use serde_json::ser::Formatter;
+use sha2::{Digest, Sha256};
+
+use crate::msgs::Msgs;
+
+/// `type_description_interfaces/msg/FieldType` ids of the ROS 2 builtin types
+const FIELD_TYPE_IDS: [(&str, u8); 14] = [
+    ("int8", 2),
+    ("uint8", 3),
+    ("char", 3),
+    ("int16", 4),
+    ("uint16", 5),
+    ("int32", 6),
+    ("uint32", 7),
+    ("int64", 8),
+    ("uint64", 9),
+    ("float32", 10),
+    ("float64", 11),
+    ("bool", 15),
+    ("byte", 16),
+    ("string", 17),
+];
+const NESTED_TYPE: u8 = 1;
+const WSTRING: u8 = 18;
+const BOUNDED_STRING: u8 = 21;
+const BOUNDED_WSTRING: u8 = 22;
+const ARRAY_OFFSET: u8 = 48;
+const BOUNDED_SEQUENCE_OFFSET: u8 = 96;
+const UNBOUNDED_SEQUENCE_OFFSET: u8 = 144;
+
+#[derive(Clone, Serialize)]
+struct TypeDescription {
+    type_name: String,
+    fields: Vec<Field>,
+}
+
+#[derive(Clone, Serialize)]
+struct Field {
+    name: String,
+    #[serde(rename = "type")]
+    ty: FieldType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_value: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct FieldType {
+    type_id: u8,
+    capacity: u64,
+    string_capacity: u64,
+    nested_type_name: String,
+}
+
+#[derive(Serialize)]
+struct SerializationData {
+    type_description: TypeDescription,
+    referenced_type_descriptions: Vec<TypeDescription>,
+}
+
+#[derive(Serialize)]
+struct TypeHash {
+    type_name: String,
+    hash_string: String,
+}
+
+/// Content of the `<Name>.json` files written by `rosidl_generator_type_description`
+#[derive(Serialize)]
+struct TypeDescriptionFile {
+    type_description_msg: SerializationData,
+    type_hashes: Vec<TypeHash>,
+}
+
+/// Formats JSON like Python's `json.dumps` with its default separators, which the hash is
+/// computed over
+struct PythonFormatter;
+
+impl Formatter for PythonFormatter {
+    fn begin_array_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b", ")
+        }
+    }
+
+    fn begin_object_key<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.begin_array_value(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        writer.write_all(b": ")
+    }
+}
+
+/// Parses the type of a field, e.g. `uint8[<=8]`, `string<=10` or `OtherMsg[]`
+fn field_type(package: &str, ty: &str) -> Result<FieldType, Box<dyn Error>> {
+    let parse_size = |size: &str| {
+        size.parse::<u64>()
+            .map_err(|_| format!("invalid size {size} in field type {ty}"))
+    };
+    let (value_type, offset, capacity) = match ty.split_once('[') {
+        None => (ty, 0, 0),
+        Some((value_type, size)) => match size.trim_end_matches(']') {
+            "" => (value_type, UNBOUNDED_SEQUENCE_OFFSET, 0),
+            size => match size.strip_prefix("<=") {
+                Some(size) => (value_type, BOUNDED_SEQUENCE_OFFSET, parse_size(size)?),
+                None => (value_type, ARRAY_OFFSET, parse_size(size)?),
+            },
+        },
+    };
+    let mut field_type = FieldType {
+        type_id: 0,
+        capacity,
+        string_capacity: 0,
+        nested_type_name: String::new(),
+    };
+    if let Some((_, id)) = FIELD_TYPE_IDS.iter().find(|(name, _)| *name == value_type) {
+        field_type.type_id = id + offset;
+    } else if value_type == "wstring" {
+        field_type.type_id = WSTRING + offset;
+    } else if let Some(size) = value_type.strip_prefix("string<=") {
+        field_type.type_id = BOUNDED_STRING + offset;
+        field_type.string_capacity = parse_size(size)?;
+    } else if let Some(size) = value_type.strip_prefix("wstring<=") {
+        field_type.type_id = BOUNDED_WSTRING + offset;
+        field_type.string_capacity = parse_size(size)?;
+    } else {
+        field_type.type_id = NESTED_TYPE + offset;
+        field_type.nested_type_name = match value_type.split_once('/') {
+            Some((other_package, name)) => format!("{other_package}/msg/{name}"),
+            None => format!("{package}/msg/{value_type}"),
+        };
+    }
+    Ok(field_type)
+}
+
+/// Parses the fields of a `.msg` definition, skipping comments and constants
+fn type_description(
+    package: &str,
+    name: &str,
+    definition: &str,
+) -> Result<TypeDescription, Box<dyn Error>> {
+    let mut fields = vec![];
+    for line in definition.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let Some((ty, rest)) = line.split_once(char::is_whitespace) else {
+            continue;
+        };
+        let (field_name, default_value) = match rest.trim_start().split_once(char::is_whitespace) {
+            Some((field_name, default_value)) => (field_name, default_value.trim()),
+            None => (rest.trim_start(), ""),
+        };
+        if field_name.contains('=') || default_value.starts_with('=') {
+            continue;
+        }
+        fields.push(Field {
+            name: field_name.to_string(),
+            ty: field_type(package, ty)?,
+            default_value: Some(default_value.to_string()),
+        });
+    }
+    // rosidl adds a placeholder member to empty messages
+    if fields.is_empty() {
+        fields.push(Field {
+            name: "structure_needs_at_least_one_member".into(),
+            ty: field_type(package, "uint8")?,
+            default_value: Some(String::new()),
+        });
+    }
+    Ok(TypeDescription {
+        type_name: format!("{package}/msg/{name}"),
+        fields,
+    })
+}
+
+/// Computes the RIHS01 hash of a type description, which excludes the default values
+fn type_hash(data: &SerializationData) -> String {
+    let without_defaults = |description: &TypeDescription| TypeDescription {
+        fields: description
+            .fields
+            .iter()
+            .map(|field| Field {
+                default_value: None,
+                ..field.clone()
+            })
+            .collect(),
+        ..description.clone()
+    };
+    let hashable = SerializationData {
+        type_description: without_defaults(&data.type_description),
+        referenced_type_descriptions: data
+            .referenced_type_descriptions
+            .iter()
+            .map(without_defaults)
+            .collect(),
+    };
+    let mut json = vec![];
+    hashable
+        .serialize(&mut serde_json::Serializer::with_formatter(
+            &mut json,
+            PythonFormatter,
+        ))
+        .unwrap();
+    format!("RIHS01_{:x}", Sha256::digest(&json))
+}
+
+impl Msgs {
+    /// Generates the ROS 2 type description `<Name>.json` of each of the given
+    /// `(name, definition)` messages of `package`, as `(file name, content)` pairs. Like the
+    /// files of `rosidl_generator_type_description`, they contain the `TypeDescription` along
+    /// with the RIHS01 hashes of the message and of the messages it references.
+    pub fn generate_type_descriptions(
+        &self,
+        package: &str,
+        messages: &[(String, String)],
+    ) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+        let descriptions = messages
+            .iter()
+            .map(|(name, definition)| {
+                let description = type_description(package, name, definition)?;
+                Ok((description.type_name.clone(), description))
+            })
+            .collect::<Result<BTreeMap<_, _>, Box<dyn Error>>>()?;
+
+        let serialization_data = |description: &TypeDescription| {
+            let mut referenced = BTreeSet::new();
+            let mut pending = vec![description];
+            while let Some(description) = pending.pop() {
+                for field in &description.fields {
+                    let nested = &field.ty.nested_type_name;
+                    if nested.is_empty() || !referenced.insert(nested.clone()) {
+                        continue;
+                    }
+                    let nested = descriptions.get(nested).ok_or_else(|| {
+                        format!(
+                            "{} references {nested}, which has no type description",
+                            description.type_name
+                        )
+                    })?;
+                    pending.push(nested);
+                }
+            }
+            Ok::<_, Box<dyn Error>>(SerializationData {
+                type_description: description.clone(),
+                referenced_type_descriptions: referenced
+                    .iter()
+                    .map(|name| descriptions[name].clone())
+                    .collect(),
+            })
+        };
+
+        let mut files = vec![];
+        for (name, _) in messages {
+            let data = serialization_data(&descriptions[&format!("{package}/msg/{name}")])?;
+            let mut type_hashes = vec![TypeHash {
+                type_name: data.type_description.type_name.clone(),
+                hash_string: type_hash(&data),
+            }];
+            for referenced in &data.referenced_type_descriptions {
+                type_hashes.push(TypeHash {
+                    type_name: referenced.type_name.clone(),
+                    hash_string: type_hash(&serialization_data(referenced)?),
+                });
+            }
+            let file = TypeDescriptionFile {
+                type_description_msg: data,
+                type_hashes,
+            };
+            files.push((
+                format!("{name}.json"),
+                serde_json::to_string_pretty(&file)? + "\n",
+            ));
+        }
+        Ok(files)
+    }
+}
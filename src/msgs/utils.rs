@@ -1,4 +1,7 @@
-use crate::common::{to_ros_const_case, to_ros_snake_case, to_ros_title_case, IntegerTypeExt};
+use crate::common::{
+    range_bounds, to_ros_const_case, to_ros_snake_case, to_ros_title_case, unit_scale,
+    IntegerTypeExt, UnitScale,
+};
 use rasn_compiler::intermediate::{
     constraints::Constraint,
     encoding_rules::per_visible::{
@@ -71,70 +74,48 @@ pub fn format_constraints(
     signed: bool,
     constraints: &Vec<Constraint>,
 ) -> Result<String, GeneratorError> {
-    if constraints.is_empty() {
-        return Ok("".into());
-    }
-    let per_constraints = per_visible_range_constraints(signed, constraints)?;
-    let range_type = int_type_token(
-        per_constraints.min::<i128>(),
-        per_constraints.max::<i128>(),
-        per_constraints.is_extensible(),
-    );
-    let range_prefix = if per_constraints.is_size_constraint() {
-        "LENGTH"
-    } else {
-        "VALUE"
+    let bounds = match range_bounds(signed, constraints)? {
+        Some(bounds) => bounds,
+        None => return Ok("".into()),
     };
-    // handle default size constraints
-    if per_constraints.is_size_constraint()
-        && !per_constraints.is_extensible()
-        && per_constraints.min::<i128>() == Some(0)
-        && per_constraints.max::<i128>().is_none()
-    {
-        return Ok("".into());
-    }
-    Ok(
-        match (
-            per_constraints.min::<i128>(),
-            per_constraints.max::<i128>(),
-            per_constraints.is_extensible(),
-        ) {
-            (Some(min), Some(max), true) if min == max => {
-                format!(
-                    "{range_type} {range_prefix}_MIN = {min}\n\
-                     {range_type} {range_prefix}_MAX = {max}"
-                )
-            }
-            (Some(min), Some(max), true) => {
-                format!(
-                    "{range_type} {range_prefix}_MIN = {min}\n\
-                     {range_type} {range_prefix}_MAX = {max}"
-                )
-            }
-            (Some(min), Some(max), false) if min == max => {
-                format!("{range_type} {range_prefix} = {min}")
-            }
-            (Some(min), Some(max), false) => {
-                format!(
-                    "{range_type} {range_prefix}_MIN = {min}\n\
-                     {range_type} {range_prefix}_MAX = {max}"
-                )
-            }
-            (Some(min), None, true) => {
-                format!("{range_type} {range_prefix}_MIN = {min}")
-            }
-            (Some(min), None, false) => {
-                format!("{range_type} {range_prefix}_MIN = {min}")
-            }
-            (None, Some(max), true) => {
-                format!("{range_type} {range_prefix}_MAX = {max}")
-            }
-            (None, Some(max), false) => {
-                format!("{range_type} {range_prefix}_MAX = {max}")
-            }
-            _ => "".into(),
-        },
-    )
+    let range_type = int_type_token(bounds.min, bounds.max, bounds.is_extensible);
+    let range_prefix = bounds.prefix();
+    Ok(match (bounds.min, bounds.max, bounds.is_extensible) {
+        (Some(min), Some(max), true) if min == max => {
+            format!(
+                "{range_type} {range_prefix}_MIN = {min}\n\
+                 {range_type} {range_prefix}_MAX = {max}"
+            )
+        }
+        (Some(min), Some(max), true) => {
+            format!(
+                "{range_type} {range_prefix}_MIN = {min}\n\
+                 {range_type} {range_prefix}_MAX = {max}"
+            )
+        }
+        (Some(min), Some(max), false) if min == max => {
+            format!("{range_type} {range_prefix} = {min}")
+        }
+        (Some(min), Some(max), false) => {
+            format!(
+                "{range_type} {range_prefix}_MIN = {min}\n\
+                 {range_type} {range_prefix}_MAX = {max}"
+            )
+        }
+        (Some(min), None, true) => {
+            format!("{range_type} {range_prefix}_MIN = {min}")
+        }
+        (Some(min), None, false) => {
+            format!("{range_type} {range_prefix}_MIN = {min}")
+        }
+        (None, Some(max), true) => {
+            format!("{range_type} {range_prefix}_MAX = {max}")
+        }
+        (None, Some(max), false) => {
+            format!("{range_type} {range_prefix}_MAX = {max}")
+        }
+        _ => "".into(),
+    })
 }
 
 pub fn format_distinguished_values(dvalues: &Option<Vec<DistinguishedValue>>) -> String {
@@ -151,6 +132,17 @@ pub fn format_distinguished_values(dvalues: &Option<Vec<DistinguishedValue>>) ->
     result
 }
 
+/// `SCALE` and `UNIT` constants of an INTEGER type, if its comments or distinguished values
+/// declare a unit
+pub fn format_unit_scale(comments: &str, dvalues: &Option<Vec<DistinguishedValue>>) -> String {
+    match unit_scale(comments, dvalues) {
+        Some(UnitScale { scale, unit }) => {
+            format!("float64 SCALE = {scale}\nstring UNIT = {unit}\n")
+        }
+        None => "".into(),
+    }
+}
+
 pub fn _format_alphabet_annotations(
     string_type: CharacterStringType,
     constraints: &Vec<Constraint>,
@@ -303,6 +295,22 @@ fn format_choice_option(
     Ok((choice_type, choice_selector))
 }
 
+/// ROS builtin type of the primitive elements of a SEQUENCE or SET OF, which have no message of
+/// their own. ROS has no nested arrays for OCTET STRING and BIT STRING elements.
+pub fn primitive_element_type(ty: &ASN1Type) -> Result<Option<String>, GeneratorError> {
+    match ty {
+        ASN1Type::Boolean(_) | ASN1Type::Integer(_) | ASN1Type::CharacterString(_) => Ok(Some(
+            constraints_and_type_name(ty, &String::new(), &String::new())?.1,
+        )),
+        ASN1Type::BitString(_) | ASN1Type::OctetString(_) => Err(error!(
+            NotYetInplemented,
+            "{} elements are currently unsupported!",
+            ty.as_str()
+        )),
+        _ => Ok(None),
+    }
+}
+
 fn constraints_and_type_name(
     ty: &ASN1Type,
     name: &String,
@@ -323,12 +331,18 @@ fn constraints_and_type_name(
             )
         }
         ASN1Type::Real(_) => (vec![], "float64".into()),
-        ASN1Type::ObjectIdentifier(_o) => todo!(),
-        ASN1Type::BitString(_b) => todo!(),
         ASN1Type::OctetString(o) => (o.constraints.clone(), "uint8[]".into()),
-        ASN1Type::GeneralizedTime(_o) => todo!(),
-        ASN1Type::UTCTime(_o) => todo!(),
-        ASN1Type::Time(_t) => todo!(),
+        ASN1Type::ObjectIdentifier(_)
+        | ASN1Type::BitString(_)
+        | ASN1Type::GeneralizedTime(_)
+        | ASN1Type::UTCTime(_)
+        | ASN1Type::Time(_) => {
+            return Err(error!(
+                NotYetInplemented,
+                "Inline {} member {name} is currently unsupported!",
+                ty.as_str()
+            ))
+        }
         ASN1Type::CharacterString(c) => (c.constraints.clone(), "string".into()),
         ASN1Type::Enumerated(_)
         | ASN1Type::Choice(_)
@@ -344,21 +358,30 @@ fn constraints_and_type_name(
         }
         ASN1Type::InformationObjectFieldReference(_)
         | ASN1Type::EmbeddedPdv
-        | ASN1Type::External => {
-            let tx = &ty.constraints().unwrap()[0];
-            let rname = if let Constraint::TableConstraint(ref tc) = tx {
-                let v = &tc.object_set.values[0];
-                if let ObjectSetValue::Reference(ref r) = v {
-                    r.clone()
-                } else {
-                    "".to_string()
-                }
-            } else {
-                "".to_string()
-            };
-            (vec![], rname)
+        | ASN1Type::External => match ty.constraints().and_then(|c| c.first()) {
+            Some(Constraint::TableConstraint(tc)) => {
+                let rname = match tc.object_set.values.first() {
+                    Some(ObjectSetValue::Reference(r)) => r.clone(),
+                    _ => "".to_string(),
+                };
+                (vec![], rname)
+            }
+            _ => {
+                return Err(error!(
+                    NotYetInplemented,
+                    "Inline {} member {name} is currently unsupported!",
+                    ty.as_str()
+                ))
+            }
+        },
+        ASN1Type::ChoiceSelectionType(c) => {
+            return Err(error!(
+                NotYetInplemented,
+                "Choice selection type {}<{} member {name} is currently unsupported!",
+                c.selected_option,
+                c.choice_name
+            ))
         }
-        ASN1Type::ChoiceSelectionType(_) => unreachable!(),
     })
 }
 
@@ -413,7 +436,10 @@ pub fn format_default_methods(
 
 pub fn type_to_tokens(ty: &ASN1Type) -> Result<String, GeneratorError> {
     match ty {
-        ASN1Type::Null => todo!(),
+        ASN1Type::Null => Err(error!(
+            NotYetInplemented,
+            "Null values are currently unsupported!"
+        )),
         ASN1Type::Boolean(_) => Ok("bool".into()),
         ASN1Type::Integer(i) => Ok(i.int_type().to_str().to_string()),
         ASN1Type::Real(_) => Ok("float64".into()),
@@ -456,11 +482,12 @@ pub fn type_to_tokens(ty: &ASN1Type) -> Result<String, GeneratorError> {
         ASN1Type::GeneralizedTime(_) => Ok("GeneralizedTime".into()),
         ASN1Type::UTCTime(_) => Ok("UtcTime".into()),
         ASN1Type::EmbeddedPdv | ASN1Type::External => Ok("Any".into()),
-        ASN1Type::ChoiceSelectionType(c) => {
-            let _choice = &c.choice_name;
-            let _option = &c.selected_option;
-            todo!()
-        }
+        ASN1Type::ChoiceSelectionType(c) => Err(error!(
+            NotYetInplemented,
+            "Choice selection type {}<{} values are currently unsupported!",
+            c.selected_option,
+            c.choice_name
+        )),
     }
 }
 
@@ -473,10 +500,16 @@ pub fn value_to_tokens(
             NotYetInplemented,
             "All values are currently unsupported!"
         )),
-        ASN1Value::Null => todo!(),
+        ASN1Value::Null => Err(error!(
+            NotYetInplemented,
+            "Null values are currently unsupported!"
+        )),
         ASN1Value::Choice { inner_value, .. } => {
-            if let Some(_) = type_name {
-                todo!()
+            if let Some(ty_n) = type_name {
+                Err(error!(
+                    NotYetInplemented,
+                    "Choice values of {ty_n} are currently unsupported!"
+                ))
             } else {
                 Err(error!(
                     Unidentified,
@@ -484,21 +517,20 @@ pub fn value_to_tokens(
                 ))
             }
         }
-        ASN1Value::OctetString(o) => {
-            let _bytes = o.iter().map(|byte| *byte);
-            todo!()
-        }
+        ASN1Value::OctetString(_) => Err(error!(
+            NotYetInplemented,
+            "Octet String values are currently unsupported!"
+        )),
         ASN1Value::SequenceOrSet(_) => Err(error!(
             Unidentified,
             "Unexpectedly encountered unlinked struct-like ASN1 value!"
         )),
-        ASN1Value::LinkedStructLikeValue(fields) => {
-            if let Some(_ty_n) = type_name {
-                let _tokenized_fields = fields
-                    .iter()
-                    .map(|(_, _, val)| value_to_tokens(val.value(), None))
-                    .collect::<Result<Vec<String>, _>>()?;
-                todo!()
+        ASN1Value::LinkedStructLikeValue(_) => {
+            if let Some(ty_n) = type_name {
+                Err(error!(
+                    NotYetInplemented,
+                    "Sequence values of {ty_n} are currently unsupported!"
+                ))
             } else {
                 Err(error!(
                     Unidentified,
@@ -510,34 +542,28 @@ pub fn value_to_tokens(
         ASN1Value::Integer(i) => Ok(i.to_string()),
         ASN1Value::String(s) => Ok(s.to_string()),
         ASN1Value::Real(r) => Ok(r.to_string()),
-        ASN1Value::BitString(b) => {
-            let _bits = b.iter().map(|bit| bit.to_string());
-            todo!()
-        }
+        ASN1Value::BitString(_) => Err(error!(
+            NotYetInplemented,
+            "Bit String values are currently unsupported!"
+        )),
         ASN1Value::EnumeratedValue {
             enumerated,
             enumerable,
         } => Ok(format!("{}_{}", enumerated, enumerable)),
         ASN1Value::LinkedElsewhereDefinedValue { identifier: e, .. }
         | ASN1Value::ElsewhereDeclaredValue { identifier: e, .. } => Ok(e.to_string()),
-        ASN1Value::ObjectIdentifier(oid) => {
-            let _arcs = oid
-                .0
-                .iter()
-                .filter_map(|arc| arc.number.map(|id| id.to_string()));
-            todo!()
-        }
-        ASN1Value::Time(_t) => match type_name {
-            Some(_time_type) => todo!(),
-            None => todo!(),
-        },
-        ASN1Value::LinkedArrayLikeValue(seq) => {
-            let _elems = seq
-                .iter()
-                .map(|v| value_to_tokens(v, None))
-                .collect::<Result<Vec<_>, _>>()?;
-            todo!()
-        }
+        ASN1Value::ObjectIdentifier(_) => Err(error!(
+            NotYetInplemented,
+            "Object Identifier values are currently unsupported!"
+        )),
+        ASN1Value::Time(_) => Err(error!(
+            NotYetInplemented,
+            "Time values are currently unsupported!"
+        )),
+        ASN1Value::LinkedArrayLikeValue(_) => Err(error!(
+            NotYetInplemented,
+            "Sequence Of values are currently unsupported!"
+        )),
         ASN1Value::LinkedNestedValue {
             supertypes: _,
             value,
@@ -552,38 +578,10 @@ pub fn value_to_tokens(
                 _ => Ok(val.to_string()),
             }
         }
-        ASN1Value::LinkedCharStringValue(string_type, value) => {
-            let _val = value;
-            match string_type {
-                CharacterStringType::NumericString => {
-                    todo!()
-                }
-                CharacterStringType::VisibleString => {
-                    todo!()
-                }
-                CharacterStringType::IA5String => {
-                    todo!()
-                }
-                CharacterStringType::UTF8String => todo!(),
-                CharacterStringType::BMPString => {
-                    todo!()
-                }
-                CharacterStringType::PrintableString => {
-                    todo!()
-                }
-                CharacterStringType::GeneralString => {
-                    todo!()
-                }
-                CharacterStringType::VideotexString
-                | CharacterStringType::GraphicString
-                | CharacterStringType::UniversalString
-                | CharacterStringType::TeletexString => Err(GeneratorError::new(
-                    None,
-                    &format!("{:?} values are currently unsupported!", string_type),
-                    GeneratorErrorType::NotYetInplemented,
-                )),
-            }
-        }
+        ASN1Value::LinkedCharStringValue(string_type, _) => Err(error!(
+            NotYetInplemented,
+            "{:?} values are currently unsupported!", string_type
+        )),
     }
 }
 
@@ -650,37 +648,6 @@ pub fn format_nested_choice_options(
         .collect::<Result<Vec<_>, _>>()
 }
 
-pub fn format_sequence_or_set_of_item_type(
-    type_name: String,
-    first_item: Option<&ASN1Value>,
-) -> String {
-    match type_name {
-        name if name == NULL => todo!(),
-        name if name == BOOLEAN => "bool".into(),
-        name if name == INTEGER => {
-            match first_item {
-                Some(ASN1Value::LinkedIntValue { integer_type, .. }) => {
-                    integer_type.to_str().into()
-                }
-                _ => "int64".into(), // best effort
-            }
-        }
-        name if name == BIT_STRING => "BitString".into(),
-        name if name == OCTET_STRING => "OctetString".into(),
-        name if name == GENERALIZED_TIME => "GeneralizedTime".into(),
-        name if name == UTC_TIME => "UtcTime".into(),
-        name if name == OBJECT_IDENTIFIER => "ObjectIdentifier".into(),
-        name if name == NUMERIC_STRING => "NumericString".into(),
-        name if name == VISIBLE_STRING => "VisibleString".into(),
-        name if name == IA5_STRING => "IA5String".into(),
-        name if name == UTF8_STRING => "UTF8String".into(),
-        name if name == BMP_STRING => "BMPString".into(),
-        name if name == PRINTABLE_STRING => "PrintableString".into(),
-        name if name == GENERAL_STRING => "GeneralString".into(),
-        name => name,
-    }
-}
-
 /// Resolves the custom syntax declared in an information object class' WITH SYNTAX clause
 pub fn resolve_standard_syntax(
     class: &InformationObjectClass,
@@ -713,7 +680,12 @@ pub fn resolve_standard_syntax(
                             key = Some(f.value.clone());
                         }
                     }
-                    InformationObjectField::ObjectSetField(_) => todo!(),
+                    InformationObjectField::ObjectSetField(_) => {
+                        return Err(error!(
+                            NotYetInplemented,
+                            "Object set fields of information objects are currently unsupported!"
+                        ))
+                    }
                 }
             } else if !class_field.is_optional {
                 return Err(GeneratorError {
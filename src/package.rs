@@ -0,0 +1,297 @@
+//! Packaging files for the generated `etsi_its_{pdu}_msgs`, `etsi_its_{pdu}_conversion` and
+//! `etsi_its_primitives_conversion` packages
+
+/// Build system of the generated ROS packages
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildSystem {
+    /// ament_cmake (ROS 2)
+    Ament,
+    /// catkin (ROS 1)
+    Catkin,
+}
+
+const AMENT_MSGS_PACKAGE_XML: &str = r#"<?xml version="1.0"?>
+<?xml-model href="http://download.ros.org/schema/package_format3.xsd" schematypens="http://www.w3.org/2001/XMLSchema"?>
+<package format="3">
+  <name>etsi_its_{pdu}_msgs</name>
+  <version>0.0.0</version>
+  <description>ROS messages for the {pdu} ASN.1 definitions</description>
+  <maintainer email="user@todo.todo">user</maintainer>
+  <license>TODO: License declaration</license>
+
+  <buildtool_depend>ament_cmake</buildtool_depend>
+  <buildtool_depend>rosidl_default_generators</buildtool_depend>
+
+  <exec_depend>rosidl_default_runtime</exec_depend>
+
+  <member_of_group>rosidl_interface_packages</member_of_group>
+
+  <export>
+    <build_type>ament_cmake</build_type>
+  </export>
+</package>
+"#;
+
+const AMENT_MSGS_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.8)
+project(etsi_its_{pdu}_msgs)
+
+find_package(ament_cmake REQUIRED)
+find_package(rosidl_default_generators REQUIRED)
+
+rosidl_generate_interfaces(${PROJECT_NAME}
+{files}
+)
+
+ament_export_dependencies(rosidl_default_runtime)
+ament_package()
+"#;
+
+const CATKIN_MSGS_PACKAGE_XML: &str = r#"<?xml version="1.0"?>
+<package format="2">
+  <name>etsi_its_{pdu}_msgs</name>
+  <version>0.0.0</version>
+  <description>ROS messages for the {pdu} ASN.1 definitions</description>
+  <maintainer email="user@todo.todo">user</maintainer>
+  <license>TODO: License declaration</license>
+
+  <buildtool_depend>catkin</buildtool_depend>
+
+  <build_depend>message_generation</build_depend>
+
+  <exec_depend>message_runtime</exec_depend>
+</package>
+"#;
+
+const CATKIN_MSGS_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.0.2)
+project(etsi_its_{pdu}_msgs)
+
+find_package(catkin REQUIRED COMPONENTS message_generation)
+
+add_message_files(DIRECTORY msg FILES
+{files}
+)
+
+generate_messages()
+
+catkin_package(CATKIN_DEPENDS message_runtime)
+"#;
+
+const AMENT_CONVERSION_PACKAGE_XML: &str = r#"<?xml version="1.0"?>
+<?xml-model href="http://download.ros.org/schema/package_format3.xsd" schematypens="http://www.w3.org/2001/XMLSchema"?>
+<package format="3">
+  <name>etsi_its_{pdu}_conversion</name>
+  <version>0.0.0</version>
+  <description>Conversion functions between ASN.1 structs and ROS messages for {pdu}</description>
+  <maintainer email="user@todo.todo">user</maintainer>
+  <license>TODO: License declaration</license>
+
+  <buildtool_depend>ament_cmake</buildtool_depend>
+
+  <depend>etsi_its_{pdu}_coding</depend>
+  <depend>etsi_its_{pdu}_msgs</depend>
+  <depend>etsi_its_primitives_conversion</depend>
+
+  <export>
+    <build_type>ament_cmake</build_type>
+  </export>
+</package>
+"#;
+
+const AMENT_CONVERSION_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.8)
+project(etsi_its_{pdu}_conversion)
+
+find_package(ament_cmake REQUIRED)
+find_package(etsi_its_{pdu}_coding REQUIRED)
+find_package(etsi_its_{pdu}_msgs REQUIRED)
+find_package(etsi_its_primitives_conversion REQUIRED)
+
+add_library(${PROJECT_NAME} INTERFACE)
+target_include_directories(${PROJECT_NAME} INTERFACE
+  $<BUILD_INTERFACE:${CMAKE_CURRENT_SOURCE_DIR}/include>
+  $<INSTALL_INTERFACE:include>
+)
+ament_target_dependencies(${PROJECT_NAME} INTERFACE
+  etsi_its_{pdu}_coding
+  etsi_its_{pdu}_msgs
+  etsi_its_primitives_conversion
+)
+
+install(DIRECTORY include/ DESTINATION include)
+install(TARGETS ${PROJECT_NAME} EXPORT export_${PROJECT_NAME})
+
+ament_export_targets(export_${PROJECT_NAME})
+ament_export_dependencies(
+  etsi_its_{pdu}_coding
+  etsi_its_{pdu}_msgs
+  etsi_its_primitives_conversion
+)
+ament_package()
+"#;
+
+const CATKIN_CONVERSION_PACKAGE_XML: &str = r#"<?xml version="1.0"?>
+<package format="2">
+  <name>etsi_its_{pdu}_conversion</name>
+  <version>0.0.0</version>
+  <description>Conversion functions between ASN.1 structs and ROS messages for {pdu}</description>
+  <maintainer email="user@todo.todo">user</maintainer>
+  <license>TODO: License declaration</license>
+
+  <buildtool_depend>catkin</buildtool_depend>
+
+  <depend>etsi_its_{pdu}_coding</depend>
+  <depend>etsi_its_{pdu}_msgs</depend>
+  <depend>etsi_its_primitives_conversion</depend>
+</package>
+"#;
+
+const CATKIN_CONVERSION_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.0.2)
+project(etsi_its_{pdu}_conversion)
+
+find_package(catkin REQUIRED COMPONENTS
+  etsi_its_{pdu}_coding
+  etsi_its_{pdu}_msgs
+  etsi_its_primitives_conversion
+)
+
+catkin_package(
+  INCLUDE_DIRS include
+  CATKIN_DEPENDS
+    etsi_its_{pdu}_coding
+    etsi_its_{pdu}_msgs
+    etsi_its_primitives_conversion
+  CFG_EXTRAS ${PROJECT_NAME}-extras.cmake
+)
+
+install(DIRECTORY include/${PROJECT_NAME}/
+  DESTINATION ${CATKIN_PACKAGE_INCLUDE_DESTINATION}
+)
+"#;
+
+/// Selects the ROS 1 branch of the conversion headers in dependent packages
+const CATKIN_CONVERSION_EXTRAS: &str = r#"add_definitions(-DROS1)
+"#;
+
+const AMENT_PRIMITIVES_PACKAGE_XML: &str = r#"<?xml version="1.0"?>
+<?xml-model href="http://download.ros.org/schema/package_format3.xsd" schematypens="http://www.w3.org/2001/XMLSchema"?>
+<package format="3">
+  <name>etsi_its_primitives_conversion</name>
+  <version>0.0.0</version>
+  <description>Conversion functions between ASN.1 primitives and ROS message fields</description>
+  <maintainer email="user@todo.todo">user</maintainer>
+  <license>TODO: License declaration</license>
+
+  <buildtool_depend>ament_cmake</buildtool_depend>
+
+  <export>
+    <build_type>ament_cmake</build_type>
+  </export>
+</package>
+"#;
+
+const AMENT_PRIMITIVES_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.8)
+project(etsi_its_primitives_conversion)
+
+find_package(ament_cmake REQUIRED)
+
+add_library(${PROJECT_NAME} INTERFACE)
+target_include_directories(${PROJECT_NAME} INTERFACE
+  $<BUILD_INTERFACE:${CMAKE_CURRENT_SOURCE_DIR}/include>
+  $<INSTALL_INTERFACE:include>
+)
+
+install(DIRECTORY include/ DESTINATION include)
+install(TARGETS ${PROJECT_NAME} EXPORT export_${PROJECT_NAME})
+
+ament_export_targets(export_${PROJECT_NAME})
+ament_package()
+"#;
+
+const CATKIN_PRIMITIVES_PACKAGE_XML: &str = r#"<?xml version="1.0"?>
+<package format="2">
+  <name>etsi_its_primitives_conversion</name>
+  <version>0.0.0</version>
+  <description>Conversion functions between ASN.1 primitives and ROS message fields</description>
+  <maintainer email="user@todo.todo">user</maintainer>
+  <license>TODO: License declaration</license>
+
+  <buildtool_depend>catkin</buildtool_depend>
+</package>
+"#;
+
+const CATKIN_PRIMITIVES_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.0.2)
+project(etsi_its_primitives_conversion)
+
+find_package(catkin REQUIRED)
+
+catkin_package(INCLUDE_DIRS include)
+
+install(DIRECTORY include/${PROJECT_NAME}/
+  DESTINATION ${CATKIN_PACKAGE_INCLUDE_DESTINATION}
+)
+"#;
+
+/// Generates `package.xml` and `CMakeLists.txt`, as `(path, content)` pairs relative to the
+/// package root, of the messages package listing the given `.msg` names
+pub fn msgs_package(build: BuildSystem, pdu: &str, msg_names: &[String]) -> Vec<(String, String)> {
+    let (package_xml, cmake, prefix) = match build {
+        BuildSystem::Ament => (AMENT_MSGS_PACKAGE_XML, AMENT_MSGS_CMAKE, "msg/"),
+        BuildSystem::Catkin => (CATKIN_MSGS_PACKAGE_XML, CATKIN_MSGS_CMAKE, ""),
+    };
+    let files = msg_names
+        .iter()
+        .map(|name| format!("  {prefix}{name}.msg"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    vec![
+        ("package.xml".into(), package_xml.replace("{pdu}", pdu)),
+        (
+            "CMakeLists.txt".into(),
+            cmake.replace("{files}", &files).replace("{pdu}", pdu),
+        ),
+    ]
+}
+
+/// Generates the packaging files, as `(path, content)` pairs relative to the package root,
+/// of the header-only conversion package
+pub fn conversion_package(build: BuildSystem, pdu: &str) -> Vec<(String, String)> {
+    match build {
+        BuildSystem::Ament => vec![
+            (
+                "package.xml".into(),
+                AMENT_CONVERSION_PACKAGE_XML.replace("{pdu}", pdu),
+            ),
+            (
+                "CMakeLists.txt".into(),
+                AMENT_CONVERSION_CMAKE.replace("{pdu}", pdu),
+            ),
+        ],
+        BuildSystem::Catkin => vec![
+            (
+                "package.xml".into(),
+                CATKIN_CONVERSION_PACKAGE_XML.replace("{pdu}", pdu),
+            ),
+            (
+                "CMakeLists.txt".into(),
+                CATKIN_CONVERSION_CMAKE.replace("{pdu}", pdu),
+            ),
+            (
+                format!("cmake/etsi_its_{pdu}_conversion-extras.cmake"),
+                CATKIN_CONVERSION_EXTRAS.into(),
+            ),
+        ],
+    }
+}
+
+/// Generates the packaging files, as `(path, content)` pairs relative to the package root,
+/// of the header-only primitives package shared by the conversion packages of all PDUs
+pub fn primitives_package(build: BuildSystem) -> Vec<(String, String)> {
+    let (package_xml, cmake) = match build {
+        BuildSystem::Ament => (AMENT_PRIMITIVES_PACKAGE_XML, AMENT_PRIMITIVES_CMAKE),
+        BuildSystem::Catkin => (CATKIN_PRIMITIVES_PACKAGE_XML, CATKIN_PRIMITIVES_CMAKE),
+    };
+    vec![
+        ("package.xml".into(), package_xml.into()),
+        ("CMakeLists.txt".into(), cmake.into()),
+    ]
+}
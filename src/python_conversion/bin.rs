@@ -0,0 +1,8 @@
+use clap::Parser;
+
+use ros_backend::cli::PythonConversionCommand;
+
+/// Alias of `asn1-to-ros python-conversion`
+fn main() {
+    PythonConversionCommand::parse().run();
+}
@@ -0,0 +1,236 @@
+use std::error::Error;
+
+use rasn_compiler::prelude::{ir::*, *};
+
+use crate::common::{to_ros_const_case, to_ros_snake_case};
+use crate::python_conversion::{template::*, utils::*};
+use crate::python_conversion::{PythonConversion, PythonConversionOptions};
+use crate::renames::Renames;
+
+impl Backend for PythonConversion {
+    fn generate_module(
+        &self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        let module = match tlds.first() {
+            Some(ToplevelDefinition::Type(t)) => t.index.as_ref(),
+            Some(ToplevelDefinition::Value(v)) => v.index.as_ref(),
+            Some(ToplevelDefinition::Information(i)) => i.index.as_ref(),
+            None => None,
+        }
+        .map(|(module, _)| module.borrow().name.clone());
+        let Some(module) = module else {
+            return Ok(GeneratedModule::empty());
+        };
+        let options = PythonConversionOptions {
+            renames: self.options.renames.in_module(&tlds),
+            ..self.options.clone()
+        };
+        let (classes, warnings): (Vec<String>, Vec<Box<dyn Error>>) =
+            tlds.into_iter().fold((vec![], vec![]), |mut acc, tld| {
+                match generate(&options, tld) {
+                    Ok(s) => {
+                        if !s.is_empty() {
+                            acc.0.push(s)
+                        }
+                        acc
+                    }
+                    Err(e) => {
+                        acc.1.push(Box::new(e));
+                        acc
+                    }
+                }
+            });
+        Ok(GeneratedModule {
+            generated: Some(module_template(&module, &classes)),
+            warnings,
+        })
+    }
+}
+
+fn generate(
+    options: &PythonConversionOptions,
+    tld: ToplevelDefinition,
+) -> Result<String, GeneratorError> {
+    match tld {
+        ToplevelDefinition::Type(t) => {
+            if t.parameterization.is_some() {
+                return Ok("".into());
+            }
+            let renames = &options.renames;
+            let name = class_name(renames.type_name(&t.name));
+            generate_type(renames, &t.name, &name, &t.ty).map_err(|mut e| {
+                e.top_level_declaration = Some(ToplevelDefinition::Type(t));
+                e
+            })
+        }
+        ToplevelDefinition::Value(_) | ToplevelDefinition::Information(_) => Ok("".into()),
+    }
+}
+
+/// Generates the class of a type and the classes of its extension addition groups
+fn generate_type(
+    renames: &Renames,
+    asn1_name: &str,
+    name: &str,
+    ty: &ASN1Type,
+) -> Result<String, GeneratorError> {
+    match ty {
+        ASN1Type::Boolean(_)
+        | ASN1Type::Integer(_)
+        | ASN1Type::OctetString(_)
+        | ASN1Type::CharacterString(_)
+        | ASN1Type::ElsewhereDeclaredType(_) => Ok(delegate_template(
+            &asn1_type_label(ty),
+            asn1_name,
+            name,
+            asn1_type_hint(ty),
+            &convert_value(renames, ty, "value", name, Direction::ToRos)?,
+            &convert_value(renames, ty, "msg.value", name, Direction::ToAsn1)?,
+        )),
+        ASN1Type::BitString(_) => Ok(bit_string_template(asn1_name, name)),
+        ASN1Type::Enumerated(e) => Ok(enumerated_template(
+            asn1_name,
+            name,
+            &e.members
+                .iter()
+                .map(|m| (m.name.clone(), to_ros_const_case(&m.name)))
+                .collect::<Vec<_>>(),
+        )),
+        ASN1Type::Choice(c) => generate_choice(renames, asn1_name, name, c),
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            generate_sequence_or_set(&asn1_type_label(ty), renames, asn1_name, name, s)
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            if is_inner_type(&s.element_type) {
+                return Err(error!(
+                    NotYetInplemented,
+                    "Anonymous {} elements are currently unsupported!",
+                    s.element_type.as_str()
+                ));
+            }
+            Ok(sequence_or_set_of_template(
+                &asn1_type_label(ty),
+                asn1_name,
+                name,
+                &convert_value(renames, ty, "value", name, Direction::ToRos)?,
+                &convert_value(renames, ty, "msg.array", name, Direction::ToAsn1)?,
+            ))
+        }
+        ty => Err(error!(
+            NotYetInplemented,
+            "{} conversions are currently unsupported!",
+            ty.as_str()
+        )),
+    }
+}
+
+fn asn1_type_label(ty: &ASN1Type) -> String {
+    match ty {
+        ASN1Type::ElsewhereDeclaredType(_) => "TYPE-ALIAS".into(),
+        ASN1Type::CharacterString(_) => "CHARACTER-STRING".into(),
+        ty => ty.as_str().replace(' ', "-"),
+    }
+}
+
+fn generate_choice(
+    renames: &Renames,
+    asn1_name: &str,
+    name: &str,
+    choice: &Choice,
+) -> Result<String, GeneratorError> {
+    let options = choice
+        .options
+        .iter()
+        .map(|o| {
+            check_inline_member(&o.ty, &o.name)?;
+            let ros_name = renames.member_name(asn1_name, &o.name);
+            let inner_class = inner_class_name(ros_name, name);
+            let field = to_ros_snake_case(ros_name);
+            Ok(ChoiceOptionConversion {
+                option: o.name.clone(),
+                constant: format!("CHOICE_{}", to_ros_const_case(ros_name)),
+                to_ros: convert_value(renames, &o.ty, "v", &inner_class, Direction::ToRos)?,
+                to_asn1: convert_value(
+                    renames,
+                    &o.ty,
+                    &format!("msg.{field}"),
+                    &inner_class,
+                    Direction::ToAsn1,
+                )?,
+                field,
+            })
+        })
+        .collect::<Result<Vec<_>, GeneratorError>>()?;
+    Ok(choice_template(asn1_name, name, &options))
+}
+
+fn generate_sequence_or_set(
+    asn1_type: &str,
+    renames: &Renames,
+    asn1_name: &str,
+    name: &str,
+    sequence_or_set: &SequenceOrSet,
+) -> Result<String, GeneratorError> {
+    let first_extension_index = sequence_or_set.extensible.unwrap_or(usize::MAX);
+    // members of extension addition groups are renamed like fields of the parent
+    let renames_key = match asn1_name.rsplit_once('.') {
+        Some((parent, group)) if group.starts_with("ext_group_") => parent,
+        _ => asn1_name,
+    };
+    let mut inner_classes = vec![];
+    let members = sequence_or_set
+        .members
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let ros_name = renames.member_name(renames_key, &m.name);
+            let inner_class = inner_class_name(ros_name, name);
+            if m.name.starts_with("ext_group_") {
+                inner_classes.push(generate_type(
+                    renames,
+                    &format!("{asn1_name}.{}", m.name),
+                    &inner_class,
+                    &m.ty,
+                )?);
+            } else {
+                check_inline_member(&m.ty, &m.name)?;
+            }
+            let field = to_ros_snake_case(ros_name);
+            match &m.ty {
+                ASN1Type::Sequence(group) if m.name.starts_with("ext_group_") => {
+                    Ok(MemberConversion::ExtensionGroup {
+                        keys: group.members.iter().map(|g| g.name.clone()).collect(),
+                        field,
+                        class: inner_class,
+                    })
+                }
+                ty => Ok(MemberConversion::Member {
+                    key: m.name.clone(),
+                    may_be_absent: m.is_optional || i >= first_extension_index,
+                    has_present_flag: m.is_optional && m.default_value.is_none(),
+                    to_ros: convert_value(
+                        renames,
+                        ty,
+                        &format!("value[\"{}\"]", m.name),
+                        &inner_class,
+                        Direction::ToRos,
+                    )?,
+                    to_asn1: convert_value(
+                        renames,
+                        ty,
+                        &format!("msg.{field}"),
+                        &inner_class,
+                        Direction::ToAsn1,
+                    )?,
+                    field,
+                }),
+            }
+        })
+        .collect::<Result<Vec<_>, GeneratorError>>()?;
+    inner_classes.insert(
+        0,
+        sequence_or_set_template(asn1_type, asn1_name, name, &members),
+    );
+    Ok(inner_classes.join("\n\n\n"))
+}
@@ -0,0 +1,40 @@
+use crate::renames::Renames;
+
+mod builder;
+mod template;
+mod utils;
+
+#[derive(Default, Clone)]
+pub struct PythonConversion {
+    options: PythonConversionOptions,
+}
+#[derive(Clone)]
+pub struct PythonConversionOptions {
+    main_pdu: String,
+    renames: Renames,
+}
+impl Default for PythonConversionOptions {
+    fn default() -> Self {
+        Self {
+            main_pdu: "pdu".into(),
+            renames: Renames::default(),
+        }
+    }
+}
+impl PythonConversion {
+    pub fn set_main_pdu_name(mut self, main_pdu_name: &str) -> Self {
+        self.options.main_pdu = main_pdu_name.to_owned();
+        self
+    }
+    /// Rename ROS messages and fields, while still keying the asn1tools values by the ASN.1 names
+    pub fn set_renames(mut self, renames: Renames) -> Self {
+        self.options.renames = renames;
+        self
+    }
+
+    /// Generates the module header importing the rclpy messages, to be prepended to the
+    /// conversion classes of all ASN.1 modules
+    pub fn generate_header(&self) -> String {
+        template::header_template(&self.options.main_pdu)
+    }
+}
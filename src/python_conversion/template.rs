@@ -0,0 +1,289 @@
+const HEADER_TEMPLATE: &str = r#""""Conversions between asn1tools values and the etsi_its_{pdu}_msgs ROS messages
+
+Each class converts one type with `to_ros(value)` and `to_asn1(msg)`.
+"""
+
+from etsi_its_{pdu}_msgs import msg as ros
+"#;
+
+const MODULE_TEMPLATE: &str = r#"
+
+# ASN.1 module {module}
+
+
+{classes}
+"#;
+
+const CLASS_TEMPLATE: &str = r#"class {name}:
+    """{asn1_type} {asn1_name}"""
+{attributes}
+    @staticmethod
+    def to_ros(value{hint}) -> ros.{name}:
+        {to_ros}
+
+    @staticmethod
+    def to_asn1(msg: ros.{name}){return_hint}:
+        {to_asn1}"#;
+
+pub fn header_template(pdu: &str) -> String {
+    HEADER_TEMPLATE.replace("{pdu}", pdu)
+}
+
+pub fn module_template(module: &str, classes: &[String]) -> String {
+    MODULE_TEMPLATE
+        .replace("{module}", module)
+        .replace("{classes}", &classes.join("\n\n\n"))
+}
+
+/// `hint` is the type hint of the asn1tools value, e.g. `: dict`
+pub fn class_template(
+    asn1_type: &str,
+    asn1_name: &str,
+    name: &str,
+    hint: &str,
+    to_ros: &str,
+    to_asn1: &str,
+) -> String {
+    class_template_with_attributes(asn1_type, asn1_name, name, hint, "", to_ros, to_asn1)
+}
+
+fn class_template_with_attributes(
+    asn1_type: &str,
+    asn1_name: &str,
+    name: &str,
+    hint: &str,
+    attributes: &str,
+    to_ros: &str,
+    to_asn1: &str,
+) -> String {
+    CLASS_TEMPLATE
+        .replace("{asn1_type}", asn1_type)
+        .replace("{asn1_name}", asn1_name)
+        .replace("{name}", name)
+        .replace("{hint}", hint)
+        .replace("{return_hint}", &hint.replace(": ", " -> "))
+        .replace("{attributes}", attributes)
+        .replace("{to_ros}", &to_ros.replace('\n', "\n        "))
+        .replace("{to_asn1}", &to_asn1.replace('\n', "\n        "))
+}
+
+/// Type wrapping a single value, mapped to the `value` field of the ROS message
+pub fn delegate_template(
+    asn1_type: &str,
+    asn1_name: &str,
+    name: &str,
+    hint: &str,
+    to_ros_value: &str,
+    to_asn1_value: &str,
+) -> String {
+    class_template(
+        asn1_type,
+        asn1_name,
+        name,
+        hint,
+        &format!("return ros.{name}(value={to_ros_value})"),
+        &format!("return {to_asn1_value}"),
+    )
+}
+
+/// asn1tools represents BIT STRINGs as `(data, number_of_bits)`
+pub fn bit_string_template(asn1_name: &str, name: &str) -> String {
+    class_template(
+        "BIT-STRING",
+        asn1_name,
+        name,
+        ": tuple",
+        &format!(
+            "data, number_of_bits = value\n\
+            return ros.{name}(value=list(data), bits_unused=(8 - number_of_bits % 8) % 8)"
+        ),
+        "return (bytes(msg.value), len(msg.value) * 8 - msg.bits_unused)",
+    )
+}
+
+/// `enumerals` are pairs of ASN.1 enumeral and ROS constant names
+pub fn enumerated_template(asn1_name: &str, name: &str, enumerals: &[(String, String)]) -> String {
+    let entries = enumerals
+        .iter()
+        .map(|(enumeral, constant)| format!("\n        \"{enumeral}\": ros.{name}.{constant},"))
+        .collect::<String>();
+    class_template_with_attributes(
+        "ENUMERATED",
+        asn1_name,
+        name,
+        ": str",
+        &format!(
+            "\n    _TO_ROS = {{{entries}\n    }}\n    \
+            _TO_ASN1 = {{v: k for k, v in _TO_ROS.items()}}\n"
+        ),
+        &format!(
+            "if value not in {name}._TO_ROS:\n    \
+                raise ValueError(f\"{asn1_name}: unknown enumeral {{value}}\")\n\
+            return ros.{name}(value={name}._TO_ROS[value])"
+        ),
+        &format!(
+            "if msg.value not in {name}._TO_ASN1:\n    \
+                raise ValueError(f\"{asn1_name}: unknown enumeral {{msg.value}}\")\n\
+            return {name}._TO_ASN1[msg.value]"
+        ),
+    )
+}
+
+/// Conversions of a CHOICE option, with the ASN.1 option, ROS field and ROS constant names
+pub struct ChoiceOptionConversion {
+    pub option: String,
+    pub field: String,
+    pub constant: String,
+    pub to_ros: String,
+    pub to_asn1: String,
+}
+
+/// asn1tools represents CHOICEs as `(option, value)`
+pub fn choice_template(asn1_name: &str, name: &str, options: &[ChoiceOptionConversion]) -> String {
+    let to_ros_branches = options
+        .iter()
+        .enumerate()
+        .map(|(i, o)| {
+            format!(
+                "{}if option == \"{}\":\n    \
+                    msg.choice = ros.{name}.{}\n    \
+                    msg.{} = {}\n",
+                if i == 0 { "" } else { "el" },
+                o.option,
+                o.constant,
+                o.field,
+                o.to_ros
+            )
+        })
+        .collect::<String>();
+    let to_asn1_branches = options
+        .iter()
+        .map(|o| {
+            format!(
+                "if msg.choice == ros.{name}.{}:\n    \
+                    return (\"{}\", {})\n",
+                o.constant, o.option, o.to_asn1
+            )
+        })
+        .collect::<String>();
+    class_template(
+        "CHOICE",
+        asn1_name,
+        name,
+        ": tuple",
+        &format!(
+            "option, v = value\n\
+            msg = ros.{name}()\n\
+            {to_ros_branches}else:\n    \
+                raise ValueError(f\"{asn1_name}: unknown choice {{option}}\")\n\
+            return msg"
+        ),
+        &format!(
+            "{to_asn1_branches}raise ValueError(f\"{asn1_name}: unknown choice {{msg.choice}}\")"
+        ),
+    )
+}
+
+/// Conversion of a SEQUENCE or SET member
+pub enum MemberConversion {
+    Member {
+        key: String,
+        field: String,
+        /// OPTIONAL, DEFAULT or extension addition member, which asn1tools may omit
+        may_be_absent: bool,
+        /// Flagged by `{field}_is_present` in ROS
+        has_present_flag: bool,
+        to_ros: String,
+        to_asn1: String,
+    },
+    /// Extension addition group, whose members asn1tools flattens into the parent value
+    ExtensionGroup {
+        keys: Vec<String>,
+        field: String,
+        class: String,
+    },
+}
+
+pub fn sequence_or_set_template(
+    asn1_type: &str,
+    asn1_name: &str,
+    name: &str,
+    members: &[MemberConversion],
+) -> String {
+    let (to_ros_members, to_asn1_members): (Vec<String>, Vec<String>) = members
+        .iter()
+        .map(|m| match m {
+            MemberConversion::Member {
+                key,
+                field,
+                may_be_absent,
+                has_present_flag,
+                to_ros,
+                to_asn1,
+            } => {
+                let present_flag = if *has_present_flag {
+                    format!("\n    msg.{field}_is_present = True")
+                } else {
+                    "".into()
+                };
+                let to_ros_member = if *may_be_absent {
+                    format!("if \"{key}\" in value:\n    msg.{field} = {to_ros}{present_flag}\n")
+                } else {
+                    format!("msg.{field} = {to_ros}\n")
+                };
+                let to_asn1_member = if *has_present_flag {
+                    format!("if msg.{field}_is_present:\n    value[\"{key}\"] = {to_asn1}\n")
+                } else {
+                    format!("value[\"{key}\"] = {to_asn1}\n")
+                };
+                (to_ros_member, to_asn1_member)
+            }
+            MemberConversion::ExtensionGroup { keys, field, class } => (
+                format!(
+                    "if any(k in value for k in ({})):\n    \
+                        msg.{field} = {class}.to_ros(value)\n    \
+                        msg.{field}_is_present = True\n",
+                    python_tuple(keys)
+                ),
+                format!(
+                    "if msg.{field}_is_present:\n    \
+                        value.update({class}.to_asn1(msg.{field}))\n"
+                ),
+            ),
+        })
+        .unzip();
+    class_template(
+        asn1_type,
+        asn1_name,
+        name,
+        ": dict",
+        &format!("msg = ros.{name}()\n{}return msg", to_ros_members.concat()),
+        &format!("value = {{}}\n{}return value", to_asn1_members.concat()),
+    )
+}
+
+/// Python tuple literal of the string `items`
+fn python_tuple(items: &[String]) -> String {
+    let items = items.iter().map(|i| format!("\"{i}\"")).collect::<Vec<_>>();
+    match items.as_slice() {
+        [item] => format!("{item},"),
+        items => items.join(", "),
+    }
+}
+
+pub fn sequence_or_set_of_template(
+    asn1_type: &str,
+    asn1_name: &str,
+    name: &str,
+    to_ros: &str,
+    to_asn1: &str,
+) -> String {
+    class_template(
+        asn1_type,
+        asn1_name,
+        name,
+        ": list",
+        &format!("return ros.{name}(array={to_ros})"),
+        &format!("return {to_asn1}"),
+    )
+}
@@ -0,0 +1,127 @@
+use rasn_compiler::prelude::{ir::*, *};
+
+use crate::common::to_ros_title_case;
+use crate::renames::Renames;
+
+macro_rules! error {
+    ($kind:ident, $($arg:tt)*) => {
+        GeneratorError {
+            details: format!($($arg)*),
+            top_level_declaration: None,
+            kind: GeneratorErrorType::$kind,
+        }
+    };
+}
+
+pub(crate) use error;
+
+/// Name of the conversion class, which is also the name of the ROS message
+pub fn class_name(name: &str) -> String {
+    to_ros_title_case(name)
+}
+
+/// Class name of an inner type declared inline as member `name` of `parent`
+pub fn inner_class_name(name: &str, parent: &str) -> String {
+    to_ros_title_case(&format!("{parent}{name}"))
+}
+
+/// Direction of a conversion
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// asn1tools value to ROS message
+    ToRos,
+    /// ROS message to asn1tools value
+    ToAsn1,
+}
+
+impl Direction {
+    fn method(self) -> &'static str {
+        match self {
+            Direction::ToRos => "to_ros",
+            Direction::ToAsn1 => "to_asn1",
+        }
+    }
+}
+
+/// Expression converting `value` of type `ty`
+///
+/// Extension addition groups are converted by the class `inner_class` generated for them.
+pub fn convert_value(
+    renames: &Renames,
+    ty: &ASN1Type,
+    value: &str,
+    inner_class: &str,
+    direction: Direction,
+) -> Result<String, GeneratorError> {
+    match ty {
+        ASN1Type::Boolean(_) | ASN1Type::Integer(_) | ASN1Type::CharacterString(_) => {
+            Ok(value.into())
+        }
+        ASN1Type::OctetString(_) => Ok(match direction {
+            Direction::ToRos => format!("list({value})"),
+            Direction::ToAsn1 => format!("bytes({value})"),
+        }),
+        ASN1Type::ElsewhereDeclaredType(d) => Ok(format!(
+            "{}.{}({value})",
+            class_name(renames.type_name(&d.identifier)),
+            direction.method()
+        )),
+        ASN1Type::Enumerated(_)
+        | ASN1Type::Choice(_)
+        | ASN1Type::Sequence(_)
+        | ASN1Type::Set(_) => Ok(format!("{inner_class}.{}({value})", direction.method())),
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            if is_inner_type(&s.element_type) {
+                return Err(error!(
+                    NotYetInplemented,
+                    "Anonymous {} elements are currently unsupported!",
+                    s.element_type.as_str()
+                ));
+            }
+            match convert_value(renames, &s.element_type, "v", inner_class, direction)? {
+                element if element == "v" => Ok(format!("list({value})")),
+                element => Ok(format!("[{element} for v in {value}]")),
+            }
+        }
+        ty => Err(error!(
+            NotYetInplemented,
+            "Inline {} conversions are currently unsupported!",
+            ty.as_str()
+        )),
+    }
+}
+
+/// The messages declare inline constructed members in the message of their parent, with no
+/// message type of their own to convert to
+pub fn check_inline_member(ty: &ASN1Type, name: &str) -> Result<(), GeneratorError> {
+    match is_inner_type(ty) {
+        true => Err(error!(
+            NotYetInplemented,
+            "Inline {} member {name} is currently unsupported!",
+            ty.as_str()
+        )),
+        false => Ok(()),
+    }
+}
+
+/// Whether an inline type gets its own inner type declaration
+pub fn is_inner_type(ty: &ASN1Type) -> bool {
+    matches!(
+        ty,
+        ASN1Type::Enumerated(_) | ASN1Type::Choice(_) | ASN1Type::Sequence(_) | ASN1Type::Set(_)
+    )
+}
+
+/// Type hint of the asn1tools value of `ty`
+pub fn asn1_type_hint(ty: &ASN1Type) -> &'static str {
+    match ty {
+        ASN1Type::Boolean(_) => ": bool",
+        ASN1Type::Integer(_) => ": int",
+        ASN1Type::Enumerated(_) | ASN1Type::CharacterString(_) => ": str",
+        ASN1Type::OctetString(_) => ": bytes",
+        ASN1Type::BitString(_) | ASN1Type::Choice(_) => ": tuple",
+        ASN1Type::Sequence(_) | ASN1Type::Set(_) => ": dict",
+        ASN1Type::SequenceOf(_) | ASN1Type::SetOf(_) => ": list",
+        _ => "",
+    }
+}
@@ -0,0 +1,137 @@
+//! Renames of the generated ROS messages and fields, e.g. to avoid ROS reserved words
+
+use std::collections::{BTreeMap, HashMap};
+
+use rasn_compiler::prelude::ir::{ASN1Type, ToplevelDefinition};
+use serde::Deserialize;
+
+/// Renames of ROS message and field names, applied alike by the msgs and conversion backends.
+/// The conversion headers keep addressing the original asn1c types and members.
+///
+/// ```toml
+/// [renames.types]
+/// "CAM-PDU-Descriptions.CAM" = "Cam"
+///
+/// [renames.members]
+/// "ReferencePosition.altitude" = "alt"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Renames {
+    /// ROS message names, keyed by `Module.Type`
+    #[serde(default)]
+    pub types: BTreeMap<String, String>,
+    /// ROS field names, keyed by `Type.member`
+    #[serde(default)]
+    pub members: BTreeMap<String, String>,
+    /// Message names keyed by the type names that are visible in the current module
+    #[serde(skip)]
+    visible_types: HashMap<String, String>,
+}
+
+impl Renames {
+    /// Renames the ROS message of the type `Module.Type`
+    pub fn set_type_name(mut self, module_type: &str, name: &str) -> Self {
+        self.types.insert(module_type.into(), name.into());
+        self
+    }
+
+    /// Renames the ROS field of the member `Type.member`
+    pub fn set_member_name(mut self, type_member: &str, name: &str) -> Self {
+        self.members.insert(type_member.into(), name.into());
+        self
+    }
+
+    /// Resolves the `Module.Type` keys against the types that the module of `tlds` defines or
+    /// imports
+    pub(crate) fn in_module(&self, tlds: &[ToplevelDefinition]) -> Renames {
+        let mut renames = Renames {
+            visible_types: HashMap::new(),
+            ..self.clone()
+        };
+        let module = tlds.iter().find_map(|tld| match tld {
+            ToplevelDefinition::Type(t) => t.index.as_ref(),
+            ToplevelDefinition::Value(v) => v.index.as_ref(),
+            ToplevelDefinition::Information(i) => i.index.as_ref(),
+        });
+        let Some((module, _)) = module else {
+            return renames;
+        };
+        let module = module.borrow();
+        for (key, name) in &self.types {
+            let Some((module_name, ty)) = key.split_once('.') else {
+                continue;
+            };
+            let is_imported = module.imports.iter().any(|import| {
+                import.global_module_reference.module_reference == module_name
+                    && import.types.iter().any(|t| t == ty)
+            });
+            if module.name == module_name || is_imported {
+                renames.visible_types.insert(ty.into(), name.clone());
+            }
+        }
+        renames
+    }
+
+    /// ROS message name of the type `ty`
+    pub(crate) fn type_name<'a>(&'a self, ty: &'a str) -> &'a str {
+        self.visible_types.get(ty).map_or(ty, String::as_str)
+    }
+
+    /// ROS field name of the member `member` of the type `ty`
+    pub(crate) fn member_name<'a>(&'a self, ty: &str, member: &'a str) -> &'a str {
+        self.members
+            .get(&format!("{ty}.{member}"))
+            .map_or(member, String::as_str)
+    }
+
+    /// Renames a definition, its members and the types it references
+    pub(crate) fn apply(&self, mut tld: ToplevelDefinition) -> ToplevelDefinition {
+        if let ToplevelDefinition::Type(t) = &mut tld {
+            self.rename_members(&t.name, &mut t.ty);
+            self.rename_references(&mut t.ty);
+            t.name = self.type_name(&t.name).to_string();
+        }
+        tld
+    }
+
+    fn rename_members(&self, ty_name: &str, ty: &mut ASN1Type) {
+        match ty {
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+                for member in &mut s.members {
+                    // members of extension addition groups are fields of the parent
+                    if member.name.starts_with("ext_group_") {
+                        self.rename_members(ty_name, &mut member.ty);
+                    }
+                    member.name = self.member_name(ty_name, &member.name).to_string();
+                }
+            }
+            ASN1Type::Choice(c) => {
+                for option in &mut c.options {
+                    option.name = self.member_name(ty_name, &option.name).to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn rename_references(&self, ty: &mut ASN1Type) {
+        match ty {
+            ASN1Type::ElsewhereDeclaredType(d) => {
+                d.identifier = self.type_name(&d.identifier).to_string();
+            }
+            ASN1Type::Sequence(s) | ASN1Type::Set(s) => s
+                .members
+                .iter_mut()
+                .for_each(|m| self.rename_references(&mut m.ty)),
+            ASN1Type::Choice(c) => c
+                .options
+                .iter_mut()
+                .for_each(|o| self.rename_references(&mut o.ty)),
+            ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+                self.rename_references(&mut s.element_type)
+            }
+            _ => {}
+        }
+    }
+}
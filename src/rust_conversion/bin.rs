@@ -0,0 +1,8 @@
+use clap::Parser;
+
+use ros_backend::cli::RustConversionCommand;
+
+/// Alias of `asn1-to-ros rust-conversion`
+fn main() {
+    RustConversionCommand::parse().run();
+}
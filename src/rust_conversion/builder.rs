@@ -0,0 +1,240 @@
+use std::error::Error;
+
+use rasn_compiler::prelude::{ir::*, *};
+
+use crate::common::to_ros_const_case;
+use crate::renames::Renames;
+use crate::rust_conversion::{template::*, utils::*};
+use crate::rust_conversion::{RosCrate, RustConversion, RustConversionOptions};
+
+impl Backend for RustConversion {
+    fn generate_module(
+        &self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        let module = match tlds.first() {
+            Some(ToplevelDefinition::Type(t)) => t.index.as_ref(),
+            Some(ToplevelDefinition::Value(v)) => v.index.as_ref(),
+            Some(ToplevelDefinition::Information(i)) => i.index.as_ref(),
+            None => None,
+        }
+        .map(|(module, _)| to_rust_snake_case(&module.borrow().name));
+        let Some(module) = module else {
+            return Ok(GeneratedModule::empty());
+        };
+        let options = RustConversionOptions {
+            renames: self.options.renames.in_module(&tlds),
+            ..self.options.clone()
+        };
+        let (impls, warnings): (Vec<String>, Vec<Box<dyn Error>>) =
+            tlds.into_iter().fold((vec![], vec![]), |mut acc, tld| {
+                match generate(&options, tld) {
+                    Ok(s) => {
+                        if !s.is_empty() {
+                            acc.0.push(s)
+                        }
+                        acc
+                    }
+                    Err(e) => {
+                        acc.1.push(Box::new(e));
+                        acc
+                    }
+                }
+            });
+        Ok(GeneratedModule {
+            generated: Some(module_template(&module, &ros_path(&self.options), &impls)),
+            warnings,
+        })
+    }
+}
+
+fn ros_path(options: &RustConversionOptions) -> String {
+    match options.ros_crate {
+        RosCrate::R2r => format!("r2r::etsi_its_{}_msgs::msg", options.main_pdu),
+        RosCrate::Rclrs => format!("etsi_its_{}_msgs::msg", options.main_pdu),
+    }
+}
+
+fn generate(
+    options: &RustConversionOptions,
+    tld: ToplevelDefinition,
+) -> Result<String, GeneratorError> {
+    match tld {
+        ToplevelDefinition::Type(t) => {
+            if t.parameterization.is_some() {
+                return Ok("".into());
+            }
+            let names = TypeNames::new(&t.name, &options.renames);
+            generate_type(&options.renames, &names, &t.ty).map_err(|mut e| {
+                e.top_level_declaration = Some(ToplevelDefinition::Type(t));
+                e
+            })
+        }
+        ToplevelDefinition::Value(_) | ToplevelDefinition::Information(_) => Ok("".into()),
+    }
+}
+
+/// Generates the conversions of a type and of its extension addition groups
+fn generate_type(
+    renames: &Renames,
+    names: &TypeNames,
+    ty: &ASN1Type,
+) -> Result<String, GeneratorError> {
+    match ty {
+        ASN1Type::Boolean(_)
+        | ASN1Type::Integer(_)
+        | ASN1Type::OctetString(_)
+        | ASN1Type::CharacterString(_)
+        | ASN1Type::ElsewhereDeclaredType(_) => Ok(delegate_template(
+            &asn1_type_label(ty),
+            names,
+            &convert_value(ty, "value.0", "\"value\"", Direction::ToRos)?,
+            &convert_value(ty, "value.value", "\"value\"", Direction::ToAsn)?,
+        )),
+        ASN1Type::BitString(_) => Ok(bit_string_template(names)),
+        ASN1Type::Enumerated(e) => Ok(enumerated_template(
+            names,
+            &e.members
+                .iter()
+                .map(|m| (to_rust_enum_identifier(&m.name), to_ros_const_case(&m.name)))
+                .collect::<Vec<_>>(),
+        )),
+        ASN1Type::Choice(c) => generate_choice(renames, names, c),
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            generate_sequence_or_set(&asn1_type_label(ty), renames, names, s)
+        }
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            generate_sequence_or_set_of(&asn1_type_label(ty), names, s)
+        }
+        ty => Err(error!(
+            NotYetInplemented,
+            "{} conversions are currently unsupported!",
+            ty.as_str()
+        )),
+    }
+}
+
+fn asn1_type_label(ty: &ASN1Type) -> String {
+    match ty {
+        ASN1Type::ElsewhereDeclaredType(_) => "TYPE-ALIAS".into(),
+        ASN1Type::CharacterString(_) => "CHARACTER-STRING".into(),
+        ty => ty.as_str().replace(' ', "-"),
+    }
+}
+
+fn generate_choice(
+    renames: &Renames,
+    names: &TypeNames,
+    choice: &Choice,
+) -> Result<String, GeneratorError> {
+    let options = choice
+        .options
+        .iter()
+        .map(|o| {
+            check_inline_member(&o.ty, &o.name)?;
+            let field = names.ros_field(&o.name, renames);
+            Ok(ChoiceOptionConversion {
+                variant: to_rust_enum_identifier(&o.name),
+                constant: format!(
+                    "CHOICE_{}",
+                    to_ros_const_case(renames.member_name(&names.asn1, &o.name))
+                ),
+                to_ros: convert_value(&o.ty, "v", &format!("\"{field}\""), Direction::ToRos)?,
+                to_asn: convert_value(
+                    &o.ty,
+                    &format!("value.{field}"),
+                    &format!("\"{field}\""),
+                    Direction::ToAsn,
+                )?,
+                field,
+            })
+        })
+        .collect::<Result<Vec<_>, GeneratorError>>()?;
+    Ok(choice_template(names, &options))
+}
+
+fn generate_sequence_or_set(
+    asn1_type: &str,
+    renames: &Renames,
+    names: &TypeNames,
+    sequence_or_set: &SequenceOrSet,
+) -> Result<String, GeneratorError> {
+    let mut inner_types = vec![];
+    let members = sequence_or_set
+        .members
+        .iter()
+        .map(|m| {
+            if m.name.starts_with("ext_group_") {
+                let inner_names = inner_names(&m.name, names, renames);
+                inner_types.push(generate_type(renames, &inner_names, &m.ty)?);
+            } else {
+                check_inline_member(&m.ty, &m.name)?;
+            }
+            let ros_field = names.ros_field(&m.name, renames);
+            let path = format!("\"{ros_field}\"");
+            let has_present_flag =
+                (m.is_optional && m.default_value.is_none()) || m.name.starts_with("ext_group_");
+            let (to_ros_value, to_asn_value) = if has_present_flag {
+                ("v".to_string(), format!("value.{ros_field}"))
+            } else {
+                (
+                    format!("value.{}", to_rust_snake_case(&m.name)),
+                    format!("value.{ros_field}"),
+                )
+            };
+            Ok(MemberConversion {
+                asn_field: to_rust_snake_case(&m.name),
+                has_present_flag,
+                to_ros: convert_value(&m.ty, &to_ros_value, &path, Direction::ToRos)?,
+                to_asn: convert_value(&m.ty, &to_asn_value, &path, Direction::ToAsn)?,
+                ros_field,
+            })
+        })
+        .collect::<Result<Vec<_>, GeneratorError>>()?;
+    inner_types.insert(0, sequence_or_set_template(asn1_type, names, &members));
+    Ok(inner_types.join("\n\n"))
+}
+
+/// Top-level SEQUENCE OF and SET OF bindings wrap elements that are not type references
+/// in an `Anonymous{Name}` newtype
+fn generate_sequence_or_set_of(
+    asn1_type: &str,
+    names: &TypeNames,
+    sequence_or_set_of: &SequenceOrSetOf,
+) -> Result<String, GeneratorError> {
+    let element_type = sequence_or_set_of.element_type.as_ref();
+    if is_inner_type(element_type) {
+        return Err(error!(
+            NotYetInplemented,
+            "Anonymous {} elements are currently unsupported!",
+            element_type.as_str()
+        ));
+    }
+    let path = "format_args!(\"array[{i}]\")";
+    let (to_ros, to_asn) = match element_type {
+        ASN1Type::ElsewhereDeclaredType(_) => (
+            convert_value(element_type, "v", path, Direction::ToRos)?,
+            convert_value(element_type, "v", path, Direction::ToAsn)?,
+        ),
+        _ => (
+            convert_value(element_type, "v.0", path, Direction::ToRos)?,
+            format!(
+                "asn::Anonymous{}({})",
+                names.asn,
+                convert_value(element_type, "v", path, Direction::ToAsn)?
+            ),
+        ),
+    };
+    Ok(sequence_or_set_of_template(
+        asn1_type,
+        names,
+        &format!(
+            "value.0.into_iter().enumerate(){}",
+            collect_elements(&to_ros)
+        ),
+        &format!(
+            "value.array.into_iter().enumerate(){}",
+            collect_elements(&to_asn)
+        ),
+    ))
+}
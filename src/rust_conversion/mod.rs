@@ -0,0 +1,49 @@
+use crate::renames::Renames;
+
+mod builder;
+mod template;
+mod utils;
+
+#[derive(Default, Clone)]
+pub struct RustConversion {
+    options: RustConversionOptions,
+}
+#[derive(Clone)]
+pub struct RustConversionOptions {
+    main_pdu: String,
+    ros_crate: RosCrate,
+    renames: Renames,
+}
+/// ROS 2 client library whose generated message structs are converted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum RosCrate {
+    /// r2r, messages in `r2r::{package}::msg`
+    #[default]
+    R2r,
+    /// rclrs, messages in `{package}::msg`
+    Rclrs,
+}
+impl Default for RustConversionOptions {
+    fn default() -> Self {
+        Self {
+            main_pdu: "pdu".into(),
+            ros_crate: RosCrate::default(),
+            renames: Renames::default(),
+        }
+    }
+}
+impl RustConversion {
+    pub fn set_main_pdu_name(mut self, main_pdu_name: &str) -> Self {
+        self.options.main_pdu = main_pdu_name.to_owned();
+        self
+    }
+    pub fn set_ros_crate(mut self, ros_crate: RosCrate) -> Self {
+        self.options.ros_crate = ros_crate;
+        self
+    }
+    /// Rename ROS messages and fields, while still converting the original rasn types and fields
+    pub fn set_renames(mut self, renames: Renames) -> Self {
+        self.options.renames = renames;
+        self
+    }
+}
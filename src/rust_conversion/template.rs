@@ -0,0 +1,243 @@
+use crate::rust_conversion::utils::TypeNames;
+
+const MODULE_TEMPLATE: &str = r#"#[allow(non_camel_case_types, non_snake_case, unused)]
+pub mod {module}_ros {
+    use super::{module} as asn;
+    use {ros_path} as ros;
+
+    /// Error converting between a rasn type and its ROS message
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConversionError(pub String);
+
+    impl ConversionError {
+        fn at(path: impl core::fmt::Display, error: impl core::fmt::Display) -> Self {
+            Self(format!("{path}: {error}"))
+        }
+    }
+
+    impl core::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for ConversionError {}
+
+{impls}
+}"#;
+
+const CONVERSION_TEMPLATE: &str = r#"    // {asn1_type} {name}
+
+    impl TryFrom<asn::{asn_type}> for ros::{ros_type} {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::{asn_type}) -> Result<Self, Self::Error> {
+            {to_ros}
+        }
+    }
+
+    impl TryFrom<ros::{ros_type}> for asn::{asn_type} {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::{ros_type}) -> Result<Self, Self::Error> {
+            {to_asn}
+        }
+    }"#;
+
+pub fn module_template(module: &str, ros_path: &str, impls: &[String]) -> String {
+    MODULE_TEMPLATE
+        .replace("{module}", module)
+        .replace("{ros_path}", ros_path)
+        .replace("{impls}", &impls.join("\n\n"))
+}
+
+pub fn conversion_template(
+    asn1_type: &str,
+    names: &TypeNames,
+    to_ros: &str,
+    to_asn: &str,
+) -> String {
+    CONVERSION_TEMPLATE
+        .replace("{asn1_type}", asn1_type)
+        .replace("{name}", &names.asn)
+        .replace("{asn_type}", &names.asn)
+        .replace("{ros_type}", &names.ros)
+        .replace("{to_ros}", &to_ros.replace('\n', "\n            "))
+        .replace("{to_asn}", &to_asn.replace('\n', "\n            "))
+}
+
+/// Newtype wrapping a single value, mapped to the `value` field of the ROS message
+pub fn delegate_template(
+    asn1_type: &str,
+    names: &TypeNames,
+    to_ros_value: &str,
+    to_asn_value: &str,
+) -> String {
+    conversion_template(
+        asn1_type,
+        names,
+        &format!("Ok(Self {{ value: {to_ros_value} }})"),
+        &format!("Ok(Self({to_asn_value}))"),
+    )
+}
+
+pub fn bit_string_template(names: &TypeNames) -> String {
+    conversion_template(
+        "BIT-STRING",
+        names,
+        "let bits_unused = ((8 - value.0.len() % 8) % 8) as u8;\n\
+        Ok(Self { value: value.0.into_vec(), bits_unused })",
+        "if value.bits_unused > 7 || (value.value.is_empty() && value.bits_unused > 0) {\n    \
+            return Err(ConversionError::at(\"bits_unused\", format_args!(\"{} is invalid for {} bytes\", value.bits_unused, value.value.len())));\n\
+        }\n\
+        let len = value.value.len() * 8 - value.bits_unused as usize;\n\
+        let mut bits = rasn::types::BitString::from_vec(value.value);\n\
+        bits.truncate(len);\n\
+        Ok(Self(bits))",
+    )
+}
+
+/// `enumerals` are pairs of rasn variant and ROS constant names
+pub fn enumerated_template(names: &TypeNames, enumerals: &[(String, String)]) -> String {
+    let to_ros_arms = enumerals
+        .iter()
+        .map(|(variant, constant)| {
+            format!(
+                "\n        asn::{}::{variant} => Self::{constant},",
+                names.asn
+            )
+        })
+        .collect::<String>();
+    let to_asn_arms = enumerals
+        .iter()
+        .map(|(variant, constant)| {
+            format!(
+                "\n    ros::{}::{constant} => Ok(Self::{variant}),",
+                names.ros
+            )
+        })
+        .collect::<String>();
+    conversion_template(
+        "ENUMERATED",
+        names,
+        &format!("Ok(Self {{\n    value: match value {{{to_ros_arms}\n    }},\n}})"),
+        &format!(
+            "match value.value {{{to_asn_arms}\n    \
+                v => Err(ConversionError::at(\"value\", format_args!(\"unknown enumeral {{v}}\"))),\n\
+            }}"
+        ),
+    )
+}
+
+/// Conversions of a CHOICE option, with the rasn variant, ROS field and ROS constant names
+pub struct ChoiceOptionConversion {
+    pub variant: String,
+    pub field: String,
+    pub constant: String,
+    pub to_ros: String,
+    pub to_asn: String,
+}
+
+pub fn choice_template(names: &TypeNames, options: &[ChoiceOptionConversion]) -> String {
+    let to_ros_arms = options
+        .iter()
+        .map(|o| {
+            format!(
+                "\n    asn::{}::{}(v) => Self {{\n        \
+                    choice: Self::{},\n        \
+                    {}: {},\n        \
+                    ..Default::default()\n    \
+                }},",
+                names.asn, o.variant, o.constant, o.field, o.to_ros
+            )
+        })
+        .collect::<String>();
+    let to_asn_arms = options
+        .iter()
+        .map(|o| {
+            format!(
+                "\n    ros::{}::{} => Ok(Self::{}({})),",
+                names.ros, o.constant, o.variant, o.to_asn
+            )
+        })
+        .collect::<String>();
+    conversion_template(
+        "CHOICE",
+        names,
+        &format!("Ok(match value {{{to_ros_arms}\n}})"),
+        &format!(
+            "match value.choice {{{to_asn_arms}\n    \
+                v => Err(ConversionError::at(\"choice\", format_args!(\"unknown choice {{v}}\"))),\n\
+            }}"
+        ),
+    )
+}
+
+/// Conversions of a SEQUENCE or SET member, with the rasn and ROS field names
+pub struct MemberConversion {
+    pub asn_field: String,
+    pub ros_field: String,
+    /// Optional in rasn and flagged by `{ros_field}_is_present` in ROS
+    pub has_present_flag: bool,
+    pub to_ros: String,
+    pub to_asn: String,
+}
+
+pub fn sequence_or_set_template(
+    asn1_type: &str,
+    names: &TypeNames,
+    members: &[MemberConversion],
+) -> String {
+    let to_ros_fields = members
+        .iter()
+        .map(|m| {
+            if m.has_present_flag {
+                format!(
+                    "\n    {ros}_is_present: value.{asn}.is_some(),\n    \
+                    {ros}: match value.{asn} {{\n        \
+                        Some(v) => {},\n        \
+                        None => Default::default(),\n    \
+                    }},",
+                    m.to_ros,
+                    ros = m.ros_field,
+                    asn = m.asn_field
+                )
+            } else {
+                format!("\n    {}: {},", m.ros_field, m.to_ros)
+            }
+        })
+        .collect::<String>();
+    let to_asn_fields = members
+        .iter()
+        .map(|m| {
+            if m.has_present_flag {
+                format!(
+                    "\n    {}: if value.{}_is_present {{ Some({}) }} else {{ None }},",
+                    m.asn_field, m.ros_field, m.to_asn
+                )
+            } else {
+                format!("\n    {}: {},", m.asn_field, m.to_asn)
+            }
+        })
+        .collect::<String>();
+    conversion_template(
+        asn1_type,
+        names,
+        &format!("Ok(Self {{{to_ros_fields}\n}})"),
+        &format!("Ok(Self {{{to_asn_fields}\n}})"),
+    )
+}
+
+pub fn sequence_or_set_of_template(
+    asn1_type: &str,
+    names: &TypeNames,
+    to_ros: &str,
+    to_asn: &str,
+) -> String {
+    conversion_template(
+        asn1_type,
+        names,
+        &format!("Ok(Self {{ array: {to_ros} }})"),
+        &format!("Ok(Self({to_asn}))"),
+    )
+}
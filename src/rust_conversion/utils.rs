@@ -0,0 +1,236 @@
+use rasn_compiler::prelude::{ir::*, *};
+
+use crate::common::{to_ros_snake_case, to_ros_title_case};
+use crate::renames::Renames;
+
+macro_rules! error {
+    ($kind:ident, $($arg:tt)*) => {
+        GeneratorError {
+            details: format!($($arg)*),
+            top_level_declaration: None,
+            kind: GeneratorErrorType::$kind,
+        }
+    };
+}
+
+pub(crate) use error;
+
+// Naming of the rasn bindings, mirroring the rasn-compiler Rust backend
+
+const RUST_KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+pub fn to_rust_snake_case(input: &str) -> String {
+    let input = input.replace('-', "_");
+    let mut lowercase = String::with_capacity(input.len());
+
+    let peekable = &mut input.chars().peekable();
+    while let Some(c) = peekable.next() {
+        if c.is_lowercase() || c == '_' || c.is_numeric() {
+            lowercase.push(c);
+            if peekable.peek().is_some_and(|next| next.is_uppercase()) {
+                lowercase.push('_');
+            }
+        } else {
+            lowercase.push(c.to_ascii_lowercase());
+        }
+    }
+    if RUST_KEYWORDS.contains(&lowercase.as_str()) {
+        String::from("r_") + &lowercase
+    } else {
+        lowercase
+    }
+}
+
+pub fn to_rust_title_case(input: &str) -> String {
+    let mut input = input.replace('-', "_");
+    let input = input.drain(..).fold(String::new(), |mut acc, c| {
+        if acc.is_empty() && c.is_lowercase() {
+            acc.push(c.to_ascii_uppercase());
+        } else if acc.ends_with('_') && c.is_uppercase() {
+            acc.pop();
+            acc.push(c);
+        } else if acc.ends_with('_') {
+            acc.pop();
+            acc.push(c.to_ascii_uppercase());
+        } else {
+            acc.push(c);
+        }
+        acc
+    });
+    if RUST_KEYWORDS.contains(&input.as_str()) {
+        String::from("R_") + &input
+    } else {
+        input
+    }
+}
+
+pub fn to_rust_enum_identifier(input: &str) -> String {
+    let formatted = input.replace('-', "_");
+    if RUST_KEYWORDS.contains(&input) {
+        format!("R_{formatted}")
+    } else {
+        formatted
+    }
+}
+
+// Naming of the ROS message structs, as emitted by the msgs backend
+
+/// Field name of a ROS message member, escaped like r2r and rclrs escape Rust keywords
+pub fn ros_field_name(input: &str) -> String {
+    let name = to_ros_snake_case(input);
+    if RUST_KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+/// Rasn and ROS type names of an inner type declared inline as member `name` of `parent`
+pub fn inner_names(name: &str, parent: &TypeNames, renames: &Renames) -> TypeNames {
+    TypeNames {
+        asn: format!("{}{}", parent.asn, to_rust_title_case(name)),
+        ros: to_ros_title_case(&format!(
+            "{}{}",
+            parent.ros,
+            renames.member_name(&parent.asn1, name)
+        )),
+        // members of extension addition groups are renamed like fields of the parent
+        asn1: match name.starts_with("ext_group_") {
+            true => parent.asn1.clone(),
+            false => format!("{}{name}", parent.asn1),
+        },
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TypeNames {
+    /// Name of the rasn type
+    pub asn: String,
+    /// Name of the ROS message
+    pub ros: String,
+    /// Name of the ASN.1 type, which the renames of its members are keyed by
+    pub asn1: String,
+}
+
+impl TypeNames {
+    pub fn new(name: &str, renames: &Renames) -> Self {
+        Self {
+            asn: to_rust_title_case(name),
+            ros: to_ros_title_case(renames.type_name(name)),
+            asn1: name.to_string(),
+        }
+    }
+
+    /// ROS field name of the member `name`
+    pub fn ros_field(&self, name: &str, renames: &Renames) -> String {
+        ros_field_name(renames.member_name(&self.asn1, name))
+    }
+}
+
+/// Direction of a conversion
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// rasn type to ROS message
+    ToRos,
+    /// ROS message to rasn type
+    ToAsn,
+}
+
+/// Expression converting `value` of type `ty`, failing with the member `path`
+///
+/// Extension addition groups are converted through the impls generated for their inner types.
+pub fn convert_value(
+    ty: &ASN1Type,
+    value: &str,
+    path: &str,
+    direction: Direction,
+) -> Result<String, GeneratorError> {
+    let try_into = format!("{value}.try_into().map_err(|e| ConversionError::at({path}, e))?");
+    match ty {
+        ASN1Type::Boolean(_) => Ok(value.into()),
+        ASN1Type::Integer(_)
+        | ASN1Type::ElsewhereDeclaredType(_)
+        | ASN1Type::Enumerated(_)
+        | ASN1Type::Choice(_)
+        | ASN1Type::Sequence(_)
+        | ASN1Type::Set(_) => Ok(try_into),
+        ASN1Type::OctetString(_) => Ok(match direction {
+            Direction::ToRos => format!("{value}.to_vec()"),
+            Direction::ToAsn => format!("{value}.into()"),
+        }),
+        ASN1Type::CharacterString(c) => match (c.ty, direction) {
+            (CharacterStringType::UTF8String, Direction::ToRos) => Ok(value.into()),
+            (
+                CharacterStringType::IA5String | CharacterStringType::VisibleString,
+                Direction::ToRos,
+            ) => Ok(format!("{value}.to_string()")),
+            (
+                CharacterStringType::UTF8String
+                | CharacterStringType::IA5String
+                | CharacterStringType::VisibleString,
+                Direction::ToAsn,
+            ) => Ok(try_into),
+            (ty, _) => Err(error!(
+                NotYetInplemented,
+                "{ty:?} conversions are currently unsupported!"
+            )),
+        },
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            if is_inner_type(&s.element_type) {
+                return Err(error!(
+                    NotYetInplemented,
+                    "Anonymous {} elements are currently unsupported!",
+                    s.element_type.as_str()
+                ));
+            }
+            let element = convert_value(
+                &s.element_type,
+                "v",
+                &format!("format_args!(\"{{}}[{{i}}]\", {path})"),
+                direction,
+            )?;
+            Ok(format!(
+                "{value}.into_iter().enumerate(){}",
+                collect_elements(&element)
+            ))
+        }
+        ty => Err(error!(
+            NotYetInplemented,
+            "Inline {} conversions are currently unsupported!",
+            ty.as_str()
+        )),
+    }
+}
+
+/// Adapter collecting the `(i, v)` items of an enumerated iterator converted by `element`
+pub fn collect_elements(element: &str) -> String {
+    format!(
+        ".map(|(i, v)| -> Result<_, ConversionError> {{ Ok({element}) }}).collect::<Result<_, _>>()?"
+    )
+}
+
+/// The messages declare inline constructed members in the message of their parent, with no
+/// message type of their own to convert to
+pub fn check_inline_member(ty: &ASN1Type, name: &str) -> Result<(), GeneratorError> {
+    match is_inner_type(ty) {
+        true => Err(error!(
+            NotYetInplemented,
+            "Inline {} member {name} is currently unsupported!",
+            ty.as_str()
+        )),
+        false => Ok(()),
+    }
+}
+
+/// Whether an inline type gets its own inner type declaration
+pub fn is_inner_type(ty: &ASN1Type) -> bool {
+    matches!(
+        ty,
+        ASN1Type::Enumerated(_) | ASN1Type::Choice(_) | ASN1Type::Sequence(_) | ASN1Type::Set(_)
+    )
+}
@@ -0,0 +1,8 @@
+use clap::Parser;
+
+use ros_backend::cli::ValidationCommand;
+
+/// Alias of `asn1-to-ros validation`
+fn main() {
+    ValidationCommand::parse().run();
+}
@@ -0,0 +1,266 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+
+use rasn_compiler::prelude::{ir::*, *};
+
+use crate::common::{
+    primitive_bounds, range_bounds, to_ros_const_case, to_ros_snake_case, to_ros_title_case,
+};
+use crate::validation::template::*;
+use crate::validation::{Validation, ValidationOptions};
+
+impl Backend for Validation {
+    fn generate_module(
+        &self,
+        tlds: Vec<ToplevelDefinition>,
+    ) -> Result<GeneratedModule, GeneratorError> {
+        // The headers only address the ROS messages, so the renames apply to the whole definition
+        let renames = self.options.renames.in_module(&tlds);
+        let tlds = tlds.into_iter().map(|tld| renames.apply(tld));
+        let (headers, warnings): (Vec<String>, Vec<Box<dyn Error>>) =
+            tlds.fold((vec![], vec![]), |mut acc, tld| {
+                match generate(&self.options, tld) {
+                    Ok(s) => {
+                        if !s.is_empty() {
+                            acc.0.push(format!("#<typedef>\n{s}\n#</typedef>"))
+                        }
+                        acc
+                    }
+                    Err(e) => {
+                        acc.1.push(Box::new(e));
+                        acc
+                    }
+                }
+            });
+        Ok(GeneratedModule {
+            generated: Some(headers.join("\n\n")),
+            warnings,
+        })
+    }
+}
+
+fn generate(
+    options: &ValidationOptions,
+    tld: ToplevelDefinition,
+) -> Result<String, GeneratorError> {
+    match tld {
+        ToplevelDefinition::Type(t) => {
+            if t.parameterization.is_some() {
+                return Ok("".into());
+            }
+            let name = to_ros_title_case(&t.name);
+            let mut header = ValidationHeader {
+                asn1_type: t.ty.as_str().replace(' ', "-"),
+                name: name.clone(),
+                ros_types: vec![],
+                dependencies: vec![],
+                functions: vec![],
+            };
+            let mut dependencies = BTreeSet::new();
+            generate_type(options, &name, &t.ty, &mut header, &mut dependencies).map_err(
+                |mut e| {
+                    e.top_level_declaration = Some(ToplevelDefinition::Type(t));
+                    e
+                },
+            )?;
+            dependencies.remove(&name);
+            header.dependencies = dependencies.into_iter().collect();
+            Ok(validation_template(options, &header))
+        }
+        ToplevelDefinition::Value(_) | ToplevelDefinition::Information(_) => Ok("".into()),
+    }
+}
+
+/// Adds the validation function of the type `ros_type`, preceded by those of its extension
+/// addition groups
+fn generate_type(
+    options: &ValidationOptions,
+    ros_type: &str,
+    ty: &ASN1Type,
+    header: &mut ValidationHeader,
+    dependencies: &mut BTreeSet<String>,
+) -> Result<(), GeneratorError> {
+    let checks = match ty {
+        ASN1Type::Integer(i) => match range_bounds(true, &i.constraints)? {
+            // values beyond the root of extensible constraints are valid
+            Some(bounds) if !bounds.is_extensible => {
+                bounds_checks(options, Some(ros_type), "value", "in.value", &bounds)
+            }
+            _ => vec![],
+        },
+        ASN1Type::BitString(b) => match range_bounds(true, &b.constraints)? {
+            Some(bounds) if !bounds.is_extensible => bounds_checks(
+                options,
+                Some(ros_type),
+                "value",
+                "(in.value.size() * 8 - in.bits_unused)",
+                &bounds,
+            ),
+            _ => vec![],
+        },
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => {
+            let mut checks = match range_bounds(true, &s.constraints)? {
+                Some(bounds) if !bounds.is_extensible => {
+                    bounds_checks(options, Some(ros_type), "array", "in.array.size()", &bounds)
+                }
+                _ => vec![],
+            };
+            if let ASN1Type::ElsewhereDeclaredType(d) = s.element_type.as_ref() {
+                dependencies.insert(to_ros_title_case(&d.identifier));
+                checks.push(array_check("array"));
+            }
+            checks
+        }
+        ASN1Type::ElsewhereDeclaredType(d) => {
+            dependencies.insert(to_ros_title_case(&d.identifier));
+            vec![member_check("in.value", "value")]
+        }
+        ASN1Type::Enumerated(e) => vec![switch_check(
+            options,
+            ros_type,
+            "value",
+            "in.value",
+            &e.members
+                .iter()
+                .map(|m| (to_ros_const_case(&m.name), vec![]))
+                .collect::<Vec<_>>(),
+        )],
+        ASN1Type::Choice(c) => {
+            let cases = c
+                .options
+                .iter()
+                .map(|o| {
+                    let field = to_ros_snake_case(&o.name);
+                    let checks = member_checks(
+                        options,
+                        &o.name,
+                        &o.ty,
+                        ros_type,
+                        &format!("in.{field}"),
+                        &field,
+                        header,
+                        dependencies,
+                    )?;
+                    Ok((format!("CHOICE_{}", to_ros_const_case(&o.name)), checks))
+                })
+                .collect::<Result<Vec<_>, GeneratorError>>()?;
+            vec![switch_check(
+                options,
+                ros_type,
+                "choice",
+                "in.choice",
+                &cases,
+            )]
+        }
+        ASN1Type::Sequence(s) | ASN1Type::Set(s) => {
+            let mut checks = vec![];
+            for m in &s.members {
+                let field = to_ros_snake_case(&m.name);
+                let member_checks = member_checks(
+                    options,
+                    &m.name,
+                    &m.ty,
+                    ros_type,
+                    &format!("in.{field}"),
+                    &field,
+                    header,
+                    dependencies,
+                )?;
+                let has_present_flag = (m.is_optional && m.default_value.is_none())
+                    || m.name.starts_with("ext_group_");
+                match member_checks.as_slice() {
+                    [] => (),
+                    [check] if has_present_flag => {
+                        checks.push(format!("if (in.{field}_is_present) {check}"))
+                    }
+                    _ if has_present_flag => checks.push(format!(
+                        "if (in.{field}_is_present) {{\n  {}\n}}",
+                        member_checks.join("\n").replace('\n', "\n  ")
+                    )),
+                    _ => checks.extend(member_checks),
+                }
+            }
+            checks
+        }
+        ASN1Type::Boolean(_)
+        | ASN1Type::OctetString(_)
+        | ASN1Type::CharacterString(_)
+        | ASN1Type::Null => vec![],
+        ty => {
+            return Err(GeneratorError::new(
+                None,
+                &format!("{} validation is currently unsupported!", ty.as_str()),
+                GeneratorErrorType::NotYetInplemented,
+            ))
+        }
+    };
+    header.ros_types.push(ros_type.to_string());
+    header
+        .functions
+        .push(function_template(options, ros_type, &checks));
+    Ok(())
+}
+
+/// Checks of a member or choice option `name` of `parent`, which recurse into message types
+#[allow(clippy::too_many_arguments)]
+fn member_checks(
+    options: &ValidationOptions,
+    name: &str,
+    ty: &ASN1Type,
+    parent: &str,
+    value: &str,
+    field: &str,
+    header: &mut ValidationHeader,
+    dependencies: &mut BTreeSet<String>,
+) -> Result<Vec<String>, GeneratorError> {
+    match ty {
+        ASN1Type::ElsewhereDeclaredType(d) => {
+            dependencies.insert(to_ros_title_case(&d.identifier));
+            Ok(vec![member_check(value, field)])
+        }
+        ASN1Type::Sequence(_) if name.starts_with("ext_group_") => {
+            let inner_type = to_ros_title_case(&format!("{parent}{name}"));
+            generate_type(options, &inner_type, ty, header, dependencies)?;
+            Ok(vec![member_check(value, field)])
+        }
+        // the messages declare inline constructed members in the message of their parent, and
+        // have no fields for the other members
+        ASN1Type::Enumerated(_)
+        | ASN1Type::Choice(_)
+        | ASN1Type::Sequence(_)
+        | ASN1Type::Set(_)
+        | ASN1Type::ObjectIdentifier(_)
+        | ASN1Type::BitString(_)
+        | ASN1Type::GeneralizedTime(_)
+        | ASN1Type::UTCTime(_)
+        | ASN1Type::Time(_) => Err(GeneratorError::new(
+            None,
+            &format!(
+                "Inline {} member {name} is currently unsupported!",
+                ty.as_str()
+            ),
+            GeneratorErrorType::NotYetInplemented,
+        )),
+        ASN1Type::SequenceOf(s) | ASN1Type::SetOf(s) => match s.element_type.as_ref() {
+            ASN1Type::ElsewhereDeclaredType(d) => {
+                dependencies.insert(to_ros_title_case(&d.identifier));
+                Ok(vec![array_check(field)])
+            }
+            _ => Ok(vec![]),
+        },
+        // the same bounds as `toStruct` checks with `--check-constraints`
+        ASN1Type::Integer(_) | ASN1Type::OctetString(_) | ASN1Type::CharacterString(_) => {
+            let value = match ty {
+                ASN1Type::Integer(_) => value.to_string(),
+                _ => format!("{value}.size()"),
+            };
+            Ok(match primitive_bounds(ty)? {
+                Some(bounds) if !bounds.is_extensible => {
+                    bounds_checks(options, None, field, &value, &bounds)
+                }
+                _ => vec![],
+            })
+        }
+        _ => Ok(vec![]),
+    }
+}
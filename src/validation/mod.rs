@@ -0,0 +1,40 @@
+use crate::conversion::RosTarget;
+use crate::renames::Renames;
+
+mod builder;
+mod template;
+
+#[derive(Default, Clone)]
+pub struct Validation {
+    options: ValidationOptions,
+}
+#[derive(Clone)]
+pub struct ValidationOptions {
+    main_pdu: String,
+    ros_target: RosTarget,
+    renames: Renames,
+}
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            main_pdu: "pdu".into(),
+            ros_target: RosTarget::default(),
+            renames: Renames::default(),
+        }
+    }
+}
+impl Validation {
+    pub fn set_main_pdu_name(mut self, main_pdu_name: &str) -> Self {
+        self.options.main_pdu = main_pdu_name.to_owned();
+        self
+    }
+    pub fn set_ros_target(mut self, ros_target: RosTarget) -> Self {
+        self.options.ros_target = ros_target;
+        self
+    }
+    /// Rename ROS messages and fields like the msgs backend does
+    pub fn set_renames(mut self, renames: Renames) -> Self {
+        self.options.renames = renames;
+        self
+    }
+}
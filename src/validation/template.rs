@@ -0,0 +1,169 @@
+use crate::common::{ros_includes, RangeBounds};
+use crate::validation::ValidationOptions;
+
+const VALIDATION_TEMPLATE: &str = r#"//// {asn1_type} {name}
+
+#pragma once
+
+#include <string>
+#include <vector>
+{validation_includes}
+{ros_includes}
+
+
+namespace etsi_its_{pdu}_validation {
+
+{functions}
+
+}"#;
+
+const REPORT_TEMPLATE: &str = r#"
+  auto report = [&](const std::string& error) {
+    valid = false;
+    if (errors) errors->push_back(path + error);
+  };"#;
+
+const FUNCTION_TEMPLATE: &str = r#"inline bool isValid(const {pdu}_msgs::{ros_type}& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;{report}
+  {checks}
+  return valid;
+}"#;
+
+/// Header of the validation functions of a type and of its inner types
+pub struct ValidationHeader {
+    pub asn1_type: String,
+    pub name: String,
+    /// ROS types of the type and its inner types, whose messages are validated
+    pub ros_types: Vec<String>,
+    /// Types whose validation headers are included
+    pub dependencies: Vec<String>,
+    /// Validation functions, with those of inner types first
+    pub functions: Vec<String>,
+}
+
+pub fn validation_template(options: &ValidationOptions, header: &ValidationHeader) -> String {
+    let pdu = &options.main_pdu;
+    let validation_includes = header
+        .dependencies
+        .iter()
+        .map(|dep| format!("\n#include <etsi_its_{pdu}_validation/validate{dep}.h>"))
+        .collect::<String>();
+    VALIDATION_TEMPLATE
+        .replace("{validation_includes}", &validation_includes)
+        .replace(
+            "{ros_includes}",
+            &ros_includes(
+                pdu,
+                options.ros_target,
+                &header
+                    .ros_types
+                    .iter()
+                    .map(|t| t.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .replace("{functions}", &header.functions.join("\n\n"))
+        .replace("{asn1_type}", &header.asn1_type)
+        .replace("{name}", &header.name)
+        .replace("{pdu}", pdu)
+}
+
+pub fn function_template(options: &ValidationOptions, ros_type: &str, checks: &[String]) -> String {
+    let checks = checks.join("\n");
+    FUNCTION_TEMPLATE
+        .replace(
+            "{report}",
+            if checks.contains("report(") {
+                REPORT_TEMPLATE
+            } else {
+                ""
+            },
+        )
+        .replace("{pdu}", &options.main_pdu)
+        .replace("{ros_type}", ros_type)
+        .replace("{checks}", &checks.replace('\n', "\n  "))
+}
+
+/// Checks of `value` against the `VALUE_*`/`LENGTH_*` constants of `ros_type`, or against the
+/// bounds themselves for inline members, which have no constants
+pub fn bounds_checks(
+    options: &ValidationOptions,
+    ros_type: Option<&str>,
+    field: &str,
+    value: &str,
+    bounds: &RangeBounds,
+) -> Vec<String> {
+    let prefix = bounds.prefix();
+    let check = |condition: &str, constant: &str, violation: &str, bound: i128| {
+        match ros_type {
+            Some(ros_type) => format!(
+                "if ({value} {condition} {pdu}_msgs::{ros_type}::{constant}) \
+                 report(\"{field}: \" + std::to_string({value}) + \" {violation} {constant} ({bound})\");",
+                pdu = &options.main_pdu,
+            ),
+            None => format!(
+                "if ({value} {condition} {bound}) \
+                 report(\"{field}: \" + std::to_string({value}) + \" {violation} {bound}\");",
+            ),
+        }
+    };
+    match (bounds.min, bounds.max) {
+        (Some(min), Some(max)) if min == max => vec![check("!=", prefix, "is not equal to", min)],
+        (min, max) => {
+            let mut checks = vec![];
+            // sizes and unsigned values cannot fall below zero
+            let is_unsigned = bounds.is_size || max.is_some();
+            if let Some(min) = min.filter(|min| *min != 0 || !is_unsigned) {
+                checks.push(check("<", &format!("{prefix}_MIN"), "is below", min));
+            }
+            if let Some(max) = max {
+                checks.push(check(">", &format!("{prefix}_MAX"), "is above", max));
+            }
+            checks
+        }
+    }
+}
+
+/// Recursive validation of the message `value`, reporting violations below `field`
+pub fn member_check(value: &str, field: &str) -> String {
+    format!("valid = isValid({value}, errors, path + \"{field}.\") && valid;")
+}
+
+pub fn array_check(field: &str) -> String {
+    format!(
+        "for (size_t i = 0; i < in.{field}.size(); i++) {{\n  \
+           valid = isValid(in.{field}[i], errors, path + \"{field}[\" + std::to_string(i) + \"].\") && valid;\n\
+         }}"
+    )
+}
+
+/// Check that `value` is one of the constants of `ros_type`, given as `cases` with the
+/// checks of the selected constant
+pub fn switch_check(
+    options: &ValidationOptions,
+    ros_type: &str,
+    field: &str,
+    value: &str,
+    cases: &[(String, Vec<String>)],
+) -> String {
+    let cases = cases
+        .iter()
+        .map(|(constant, body)| {
+            format!(
+                "  case {pdu}_msgs::{ros_type}::{constant}:\n{body}    break;\n",
+                pdu = &options.main_pdu,
+                body = body
+                    .iter()
+                    .map(|line| format!("    {line}\n"))
+                    .collect::<String>()
+            )
+        })
+        .collect::<String>();
+    format!(
+        "switch ({value}) {{\n\
+         {cases}  \
+           default:\n    \
+             report(\"{field}: \" + std::to_string({value}) + \" is not a known constant\");\n\
+         }}"
+    )
+}
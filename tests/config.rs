@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use ros_backend::cli::{Config, ConversionConfig, MsgsConfig};
+use ros_backend::conversion::RosTarget;
+use ros_backend::package::BuildSystem;
+use ros_backend::renames::Renames;
+
+#[test]
+fn config_paths_are_relative_to_file() {
+    let config = Config::parse(
+        r#"
+pdu = "cam"
+out = "generated"
+inputs = ["asn1/*.asn", "/abs/cdd.asn"]
+exclude = ["test/*"]
+package = "ament"
+
+[conversion]
+ros = "ros2"
+unit_helpers = true
+
+[msgs]
+type_descriptions = true
+
+[renames.members]
+"Pos.type" = "kind"
+"#,
+        Path::new("project"),
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            pdu: Some("cam".into()),
+            out: Some(PathBuf::from("project/generated")),
+            inputs: vec!["project/asn1/*.asn".into(), "/abs/cdd.asn".into()],
+            include: vec![],
+            exclude: vec!["test/*".into()],
+            package: Some(BuildSystem::Ament),
+            deny_warnings: false,
+            msgs: MsgsConfig {
+                type_descriptions: true,
+                ..Default::default()
+            },
+            conversion: ConversionConfig {
+                ros: Some(RosTarget::Ros2),
+                unit_helpers: true,
+                ..Default::default()
+            },
+            renames: Renames::default().set_member_name("Pos.type", "kind"),
+        }
+    );
+}
+
+#[test]
+fn config_stdin_input_is_not_resolved() {
+    let config = Config::parse("inputs = [\"-\"]", Path::new("project")).unwrap();
+    assert_eq!(config.inputs, vec!["-".to_string()]);
+    assert_eq!(config.input_paths().unwrap(), vec![PathBuf::from("-")]);
+}
+
+#[test]
+fn config_rejects_unknown_keys() {
+    let error = Config::parse("pdu = \"cam\"\ncheck_constraints = true", Path::new(""))
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("unknown field `check_constraints`"),
+        "{error}"
+    );
+}
+
+#[test]
+fn configured_primitives_are_packaged_next_to_the_conversion_package() {
+    let dir = std::env::temp_dir().join(format!("asn1-to-ros-primitives-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("asn1-to-ros.toml"),
+        "[conversion]\nprimitives = true\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("a.asn"),
+        "TestModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN Speed ::= INTEGER (0..100) END",
+    )
+    .unwrap();
+    let status = std::process::Command::new(env!("CARGO_BIN_EXE_asn1-to-ros-conversion-headers"))
+        .args(["-p", "test", "-o", "out", "--package", "ament", "a.asn"])
+        .current_dir(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let out = dir.join("out");
+    assert!(out
+        .join("etsi_its_test_conversion/include/etsi_its_test_conversion/convertSpeed.h")
+        .exists());
+    assert!(out.join("etsi_its_test_conversion/package.xml").exists());
+    let primitives = out.join("etsi_its_primitives_conversion");
+    assert!(primitives
+        .join("include/etsi_its_primitives_conversion/convertINTEGER.h")
+        .exists());
+    assert!(std::fs::read_to_string(primitives.join("package.xml"))
+        .unwrap()
+        .contains("<name>etsi_its_primitives_conversion</name>"));
+    assert!(std::fs::read_to_string(primitives.join("CMakeLists.txt"))
+        .unwrap()
+        .contains("project(etsi_its_primitives_conversion)"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
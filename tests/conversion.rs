@@ -5,13 +5,14 @@ e2e_hs!(
     r#" SingleByte ::= INTEGER (0..255)"#,
     r#" 
 #pragma once
+#include <memory>
 #include <stdexcept>
 
 #include <etsi_its_test_coding/SingleByte.h>
 #include <etsi_its_test_coding/INTEGER.h>
 #include <etsi_its_primitives_conversion/convertINTEGER.h>
 #ifdef ROS1
-#include <etsi_its_test_msgs/SingleByte.hpp>
+#include <etsi_its_test_msgs/SingleByte.h>
 namespace test_msgs = etsi_its_test_msgs;
 #else
 #include <etsi_its_test_msgs/msg/single_byte.hpp>
@@ -20,6 +21,11 @@ namespace test_msgs = etsi_its_test_msgs::msg;
 
 namespace etsi_its_test_conversion {
 
+struct Free_SingleByte {
+  void operator()(SingleByte_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_SingleByte, ptr); }
+};
+using SingleByte_ptr = std::unique_ptr<SingleByte_t, Free_SingleByte>;
+
 void toRos_SingleByte(const SingleByte_t& in, test_msgs::SingleByte& out) {
   etsi_its_primitives_conversion::toRos_INTEGER(in, out.value);
 }
@@ -27,7 +33,20 @@ void toRos_SingleByte(const SingleByte_t& in, test_msgs::SingleByte& out) {
 void toStruct_SingleByte(const test_msgs::SingleByte& in, SingleByte_t& out) {
   memset(&out, 0, sizeof(SingleByte_t));
 
-  etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);
+  try {
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_SingleByte, &out);
+    memset(&out, 0, sizeof(SingleByte_t));
+    throw;
+  }
+}
+
+SingleByte_ptr toStruct_SingleByte(const test_msgs::SingleByte& in) {
+  SingleByte_ptr out((SingleByte_t*) calloc(1, sizeof(SingleByte_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_SingleByte(in, *out);
+  return out;
 }
 
 }"#
@@ -38,13 +57,14 @@ e2e_hs!(
     r#" Unbound ::= INTEGER "#,
     r#" 
 #pragma once
+#include <memory>
 #include <stdexcept>
 
 #include <etsi_its_test_coding/Unbound.h>
 #include <etsi_its_test_coding/INTEGER.h>
 #include <etsi_its_primitives_conversion/convertINTEGER.h>
 #ifdef ROS1
-#include <etsi_its_test_msgs/Unbound.hpp>
+#include <etsi_its_test_msgs/Unbound.h>
 namespace test_msgs = etsi_its_test_msgs;
 #else
 #include <etsi_its_test_msgs/msg/unbound.hpp>
@@ -53,6 +73,11 @@ namespace test_msgs = etsi_its_test_msgs::msg;
 
 namespace etsi_its_test_conversion {
 
+struct Free_Unbound {
+  void operator()(Unbound_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Unbound, ptr); }
+};
+using Unbound_ptr = std::unique_ptr<Unbound_t, Free_Unbound>;
+
 void toRos_Unbound(const Unbound_t& in, test_msgs::Unbound& out) {
   etsi_its_primitives_conversion::toRos_INTEGER(in, out.value);
 }
@@ -60,7 +85,20 @@ void toRos_Unbound(const Unbound_t& in, test_msgs::Unbound& out) {
 void toStruct_Unbound(const test_msgs::Unbound& in, Unbound_t& out) {
   memset(&out, 0, sizeof(Unbound_t));
 
-  etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);
+  try {
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Unbound, &out);
+    memset(&out, 0, sizeof(Unbound_t));
+    throw;
+  }
+}
+
+Unbound_ptr toStruct_Unbound(const test_msgs::Unbound& in) {
+  Unbound_ptr out((Unbound_t*) calloc(1, sizeof(Unbound_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Unbound(in, *out);
+  return out;
 }
 
 }"#
@@ -71,15 +109,14 @@ e2e_hs!(
     r#" Seq ::= SEQUENCE { aBigNumber INTEGER, anotherBigNumber INTEGER} "#,
     r#" 
 #pragma once
+#include <memory>
 #include <stdexcept>
 
 #include <etsi_its_test_coding/Seq.h>
 #include <etsi_its_test_coding/INTEGER.h>
 #include <etsi_its_primitives_conversion/convertINTEGER.h>
-#include <etsi_its_test_coding/INTEGER.h>
-#include <etsi_its_primitives_conversion/convertINTEGER.h>
 #ifdef ROS1
-#include <etsi_its_test_msgs/Seq.hpp>
+#include <etsi_its_test_msgs/Seq.h>
 namespace test_msgs = etsi_its_test_msgs;
 #else
 #include <etsi_its_test_msgs/msg/seq.hpp>
@@ -88,6 +125,11 @@ namespace test_msgs = etsi_its_test_msgs::msg;
 
 namespace etsi_its_test_conversion {
 
+struct Free_Seq {
+  void operator()(Seq_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Seq, ptr); }
+};
+using Seq_ptr = std::unique_ptr<Seq_t, Free_Seq>;
+
 void toRos_Seq(const Seq_t& in, test_msgs::Seq& out) {
   etsi_its_primitives_conversion::toRos_INTEGER(in.aBigNumber, out.a_big_number);
   etsi_its_primitives_conversion::toRos_INTEGER(in.anotherBigNumber, out.another_big_number);
@@ -96,8 +138,21 @@ void toRos_Seq(const Seq_t& in, test_msgs::Seq& out) {
 void toStruct_Seq(const test_msgs::Seq& in, Seq_t& out) {
   memset(&out, 0, sizeof(Seq_t));
 
-  etsi_its_primitives_conversion::toStruct_INTEGER(in.a_big_number, out.aBigNumber);
-  etsi_its_primitives_conversion::toStruct_INTEGER(in.another_big_number, out.anotherBigNumber);
+  try {
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.a_big_number, out.aBigNumber);
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.another_big_number, out.anotherBigNumber);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Seq, &out);
+    memset(&out, 0, sizeof(Seq_t));
+    throw;
+  }
+}
+
+Seq_ptr toStruct_Seq(const test_msgs::Seq& in) {
+  Seq_ptr out((Seq_t*) calloc(1, sizeof(Seq_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Seq(in, *out);
+  return out;
 }
 
 }"#
@@ -107,13 +162,14 @@ e2e_hs!(boolean,
     r#" Maybe ::= BOOLEAN "#, 
     r#" 
 #pragma once
+#include <memory>
 #include <stdexcept>
 
 #include <etsi_its_test_coding/Maybe.h>
 #include <etsi_its_test_coding/BOOLEAN.h>
 #include <etsi_its_primitives_conversion/convertBOOLEAN.h>
 #ifdef ROS1
-#include <etsi_its_test_msgs/Maybe.hpp>
+#include <etsi_its_test_msgs/Maybe.h>
 namespace test_msgs = etsi_its_test_msgs;
 #else
 #include <etsi_its_test_msgs/msg/maybe.hpp>
@@ -122,6 +178,11 @@ namespace test_msgs = etsi_its_test_msgs::msg;
 
 namespace etsi_its_test_conversion {
 
+struct Free_Maybe {
+  void operator()(Maybe_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Maybe, ptr); }
+};
+using Maybe_ptr = std::unique_ptr<Maybe_t, Free_Maybe>;
+
 void toRos_Maybe(const Maybe_t& in, test_msgs::Maybe& out) {
   etsi_its_primitives_conversion::toRos_BOOLEAN(in, out.value);
 }
@@ -129,7 +190,1065 @@ void toRos_Maybe(const Maybe_t& in, test_msgs::Maybe& out) {
 void toStruct_Maybe(const test_msgs::Maybe& in, Maybe_t& out) {
   memset(&out, 0, sizeof(Maybe_t));
 
-  etsi_its_primitives_conversion::toStruct_BOOLEAN(in.value, out);
+  try {
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Maybe, &out);
+    memset(&out, 0, sizeof(Maybe_t));
+    throw;
+  }
+}
+
+Maybe_ptr toStruct_Maybe(const test_msgs::Maybe& in) {
+  Maybe_ptr out((Maybe_t*) calloc(1, sizeof(Maybe_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Maybe(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    set_of_primitive,
+    r#" Flags ::= SET OF BOOLEAN "#,
+    r#" 
+#pragma once
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Flags.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Flags.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/flags.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif 
+
+namespace etsi_its_test_conversion {
+
+struct Free_Flags {
+  void operator()(Flags_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Flags, ptr); }
+};
+using Flags_ptr = std::unique_ptr<Flags_t, Free_Flags>;
+
+void toRos_Flags(const Flags_t& in, test_msgs::Flags& out) {
+  for (int i = 0; i < in.list.count; ++i) {
+    decltype(out.array)::value_type el;
+    etsi_its_primitives_conversion::toRos_BOOLEAN(*(in.list.array[i]), el);
+    out.array.push_back(el);
+  }
+}
+
+void toStruct_Flags(const test_msgs::Flags& in, Flags_t& out) {
+  memset(&out, 0, sizeof(Flags_t));
+
+  try {
+    for (int i = 0; i < in.array.size(); ++i) {
+      BOOLEAN_t* el = (BOOLEAN_t*) calloc(1, sizeof(BOOLEAN_t));
+      if (!el) throw std::bad_alloc();
+      if (asn_set_add(&out, el)) {
+        free(el);
+        throw std::invalid_argument("Failed to add to A_SET_OF");
+      }
+      etsi_its_primitives_conversion::toStruct_BOOLEAN(in.array[i], *el);
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Flags, &out);
+    memset(&out, 0, sizeof(Flags_t));
+    throw;
+  }
+}
+
+Flags_ptr toStruct_Flags(const test_msgs::Flags& in) {
+  Flags_ptr out((Flags_t*) calloc(1, sizeof(Flags_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Flags(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_sorted_includes,
+    r#" Mixed ::= SEQUENCE { flag BOOLEAN, count INTEGER, other BOOLEAN } "#,
+    r#" 
+#pragma once
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Mixed.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Mixed.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/mixed.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif 
+
+namespace etsi_its_test_conversion {
+
+struct Free_Mixed {
+  void operator()(Mixed_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Mixed, ptr); }
+};
+using Mixed_ptr = std::unique_ptr<Mixed_t, Free_Mixed>;
+
+void toRos_Mixed(const Mixed_t& in, test_msgs::Mixed& out) {
+  etsi_its_primitives_conversion::toRos_BOOLEAN(in.flag, out.flag);
+  etsi_its_primitives_conversion::toRos_INTEGER(in.count, out.count);
+  etsi_its_primitives_conversion::toRos_BOOLEAN(in.other, out.other);
+}
+
+void toStruct_Mixed(const test_msgs::Mixed& in, Mixed_t& out) {
+  memset(&out, 0, sizeof(Mixed_t));
+
+  try {
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.flag, out.flag);
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.count, out.count);
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.other, out.other);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Mixed, &out);
+    memset(&out, 0, sizeof(Mixed_t));
+    throw;
+  }
+}
+
+Mixed_ptr toStruct_Mixed(const test_msgs::Mixed& in) {
+  Mixed_ptr out((Mixed_t*) calloc(1, sizeof(Mixed_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Mixed(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_of_native_integer,
+    r#" LI ::= SEQUENCE OF INTEGER (0..7) "#,
+    r#"
+
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/LI.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/LI.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/li.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif
+
+
+namespace etsi_its_test_conversion {
+
+struct Free_LI {
+  void operator()(LI_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_LI, ptr); }
+};
+using LI_ptr = std::unique_ptr<LI_t, Free_LI>;
+
+void toRos_LI(const LI_t& in, test_msgs::LI& out) {
+  for (int i = 0; i < in.list.count; ++i) {
+    decltype(out.array)::value_type el;
+    etsi_its_primitives_conversion::toRos_INTEGER(*(in.list.array[i]), el);
+    out.array.push_back(el);
+  }
+}
+
+void toStruct_LI(const test_msgs::LI& in, LI_t& out) {
+  memset(&out, 0, sizeof(LI_t));
+
+  try {
+    for (int i = 0; i < in.array.size(); ++i) {
+      long* el = (long*) calloc(1, sizeof(long));
+      if (!el) throw std::bad_alloc();
+      if (asn_sequence_add(&out, el)) {
+        free(el);
+        throw std::invalid_argument("Failed to add to A_SEQUENCE_OF");
+      }
+      etsi_its_primitives_conversion::toStruct_INTEGER(in.array[i], *el);
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_LI, &out);
+    memset(&out, 0, sizeof(LI_t));
+    throw;
+  }
+}
+
+LI_ptr toStruct_LI(const test_msgs::LI& in) {
+  LI_ptr out((LI_t*) calloc(1, sizeof(LI_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_LI(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_of_checked,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_check_constraints(true),
+    r#" Speeds ::= SEQUENCE (SIZE(1..8)) OF Speed "#,
+    r#" 
+#pragma once
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Speeds.h>
+#include <etsi_its_test_conversion/convertSpeed.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Speeds.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/speeds.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif 
+
+namespace etsi_its_test_conversion {
+
+struct Free_Speeds {
+  void operator()(Speeds_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Speeds, ptr); }
+};
+using Speeds_ptr = std::unique_ptr<Speeds_t, Free_Speeds>;
+
+void toRos_Speeds(const Speeds_t& in, test_msgs::Speeds& out) {
+  for (int i = 0; i < in.list.count; ++i) {
+    test_msgs::Speed el;
+    toRos_Speed(*(in.list.array[i]), el);
+    out.array.push_back(el);
+  }
+}
+
+void toStruct_Speeds(const test_msgs::Speeds& in, Speeds_t& out) {
+  memset(&out, 0, sizeof(Speeds_t));
+
+  try {
+    if (in.array.size() < test_msgs::Speeds::LENGTH_MIN) throw std::out_of_range("array: " + std::to_string(in.array.size()) + " is below LENGTH_MIN (1)");
+    if (in.array.size() > test_msgs::Speeds::LENGTH_MAX) throw std::out_of_range("array: " + std::to_string(in.array.size()) + " is above LENGTH_MAX (8)");
+    for (int i = 0; i < in.array.size(); ++i) {
+      Speed_t* el = (Speed_t*) calloc(1, sizeof(Speed_t));
+      if (!el) throw std::bad_alloc();
+      if (asn_sequence_add(&out, el)) {
+        free(el);
+        throw std::invalid_argument("Failed to add to A_SEQUENCE_OF");
+      }
+      try {
+        toStruct_Speed(in.array[i], *el);
+      } catch (const std::out_of_range& e) {
+        throw std::out_of_range("array[" + std::to_string(i) + "]." + std::string(e.what()));
+      }
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Speeds, &out);
+    memset(&out, 0, sizeof(Speeds_t));
+    throw;
+  }
+}
+
+Speeds_ptr toStruct_Speeds(const test_msgs::Speeds& in) {
+  Speeds_ptr out((Speeds_t*) calloc(1, sizeof(Speeds_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Speeds(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_members_checked,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_check_constraints(true),
+    r#" Pos ::= SEQUENCE { speed INTEGER (0..100), name IA5String (SIZE(1..8)) OPTIONAL } "#,
+    r#"
+
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Pos.h>
+#include <etsi_its_test_coding/IA5String.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertIA5String.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Pos.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/pos.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif
+
+
+namespace etsi_its_test_conversion {
+
+struct Free_Pos {
+  void operator()(Pos_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Pos, ptr); }
+};
+using Pos_ptr = std::unique_ptr<Pos_t, Free_Pos>;
+
+void toRos_Pos(const Pos_t& in, test_msgs::Pos& out) {
+  etsi_its_primitives_conversion::toRos_INTEGER(in.speed, out.speed);
+  if (in.name) {
+    etsi_its_primitives_conversion::toRos_IA5String(*in.name, out.name);
+    out.name_is_present = true;
+  }
+}
+
+void toStruct_Pos(const test_msgs::Pos& in, Pos_t& out) {
+  memset(&out, 0, sizeof(Pos_t));
+
+  try {
+    if (in.speed > 100) throw std::out_of_range("speed: " + std::to_string(in.speed) + " is above 100");
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.speed, out.speed);
+    if (in.name_is_present) {
+      out.name = (decltype(out.name)) calloc(1, sizeof(*out.name));
+      if (!out.name) throw std::bad_alloc();
+      if (in.name.size() < 1) throw std::out_of_range("name: " + std::to_string(in.name.size()) + " is below 1");
+      if (in.name.size() > 8) throw std::out_of_range("name: " + std::to_string(in.name.size()) + " is above 8");
+      etsi_its_primitives_conversion::toStruct_IA5String(in.name, *out.name);
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Pos, &out);
+    memset(&out, 0, sizeof(Pos_t));
+    throw;
+  }
+}
+
+Pos_ptr toStruct_Pos(const test_msgs::Pos& in) {
+  Pos_ptr out((Pos_t*) calloc(1, sizeof(Pos_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Pos(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    choice_options_checked,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_check_constraints(true),
+    r#" Mode ::= CHOICE { level INTEGER (-5..5), flag BOOLEAN } "#,
+    r#"
+
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Mode.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Mode.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/mode.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif
+
+
+namespace etsi_its_test_conversion {
+
+struct Free_Mode {
+  void operator()(Mode_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Mode, ptr); }
+};
+using Mode_ptr = std::unique_ptr<Mode_t, Free_Mode>;
+
+void toRos_Mode(const Mode_t& in, test_msgs::Mode& out) {
+  switch (in.present) {
+  case Mode_PR_level:
+    etsi_its_primitives_conversion::toRos_INTEGER(in.choice.level, out.level);
+    out.choice = test_msgs::Mode::CHOICE_LEVEL;
+    break;
+  case Mode_PR_flag:
+    etsi_its_primitives_conversion::toRos_BOOLEAN(in.choice.flag, out.flag);
+    out.choice = test_msgs::Mode::CHOICE_FLAG;
+    break;
+  default: break;
+  }
+}
+
+void toStruct_Mode(const test_msgs::Mode& in, Mode_t& out) {
+  memset(&out, 0, sizeof(Mode_t));
+
+  try {
+    switch (in.choice) {
+    case test_msgs::Mode::CHOICE_LEVEL:
+      if (in.level < -5) throw std::out_of_range("level: " + std::to_string(in.level) + " is below -5");
+      if (in.level > 5) throw std::out_of_range("level: " + std::to_string(in.level) + " is above 5");
+      etsi_its_primitives_conversion::toStruct_INTEGER(in.level, out.choice.level);
+      out.present = Mode_PR::Mode_PR_level;
+      break;
+    case test_msgs::Mode::CHOICE_FLAG:
+      etsi_its_primitives_conversion::toStruct_BOOLEAN(in.flag, out.choice.flag);
+      out.present = Mode_PR::Mode_PR_flag;
+      break;
+    default: break;
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Mode, &out);
+    memset(&out, 0, sizeof(Mode_t));
+    throw;
+  }
+}
+
+Mode_ptr toStruct_Mode(const test_msgs::Mode& in) {
+  Mode_ptr out((Mode_t*) calloc(1, sizeof(Mode_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Mode(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_extensions,
+    r#" Ext ::= SEQUENCE { a INTEGER, ..., b BOOLEAN, [[ c INTEGER, d BOOLEAN OPTIONAL ]], e BOOLEAN OPTIONAL } "#,
+    r#" 
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Ext.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Ext.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/ext.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif
+
+namespace etsi_its_test_conversion {
+
+struct Free_Ext {
+  void operator()(Ext_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Ext, ptr); }
+};
+using Ext_ptr = std::unique_ptr<Ext_t, Free_Ext>;
+
+void toRos_Ext(const Ext_t& in, test_msgs::Ext& out) {
+  etsi_its_primitives_conversion::toRos_INTEGER(in.a, out.a);
+  if (in.b) {
+    etsi_its_primitives_conversion::toRos_BOOLEAN(*in.b, out.b);
+  }
+  if (in.ext1) {
+    etsi_its_primitives_conversion::toRos_INTEGER(in.ext1->c, out.ext_group_c.c);
+    if (in.ext1->d) {
+      etsi_its_primitives_conversion::toRos_BOOLEAN(*in.ext1->d, out.ext_group_c.d);
+      out.ext_group_c.d_is_present = true;
+    }
+    out.ext_group_c_is_present = true;
+  }
+  if (in.e) {
+    etsi_its_primitives_conversion::toRos_BOOLEAN(*in.e, out.e);
+    out.e_is_present = true;
+  }
+}
+
+void toStruct_Ext(const test_msgs::Ext& in, Ext_t& out) {
+  memset(&out, 0, sizeof(Ext_t));
+
+  try {
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.a, out.a);
+    out.b = (decltype(out.b)) calloc(1, sizeof(*out.b));
+    if (!out.b) throw std::bad_alloc();
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.b, *out.b);
+    if (in.ext_group_c_is_present) {
+      out.ext1 = (decltype(out.ext1)) calloc(1, sizeof(*out.ext1));
+      if (!out.ext1) throw std::bad_alloc();
+      etsi_its_primitives_conversion::toStruct_INTEGER(in.ext_group_c.c, out.ext1->c);
+      if (in.ext_group_c.d_is_present) {
+        out.ext1->d = (decltype(out.ext1->d)) calloc(1, sizeof(*out.ext1->d));
+        if (!out.ext1->d) throw std::bad_alloc();
+        etsi_its_primitives_conversion::toStruct_BOOLEAN(in.ext_group_c.d, *out.ext1->d);
+      }
+    }
+    if (in.e_is_present) {
+      out.e = (decltype(out.e)) calloc(1, sizeof(*out.e));
+      if (!out.e) throw std::bad_alloc();
+      etsi_its_primitives_conversion::toStruct_BOOLEAN(in.e, *out.e);
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Ext, &out);
+    memset(&out, 0, sizeof(Ext_t));
+    throw;
+  }
+}
+
+Ext_ptr toStruct_Ext(const test_msgs::Ext& in) {
+  Ext_ptr out((Ext_t*) calloc(1, sizeof(Ext_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Ext(in, *out);
+  return out;
+}
+
+}"#
+);
+
+#[test]
+fn primitives_cover_referenced_types() {
+    let primitives = ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .generate_primitives();
+    let names: Vec<&str> = primitives.iter().map(|(name, _)| name.as_str()).collect();
+    for name in [
+        "convertINTEGER.h",
+        "convertBOOLEAN.h",
+        "convertOCTET_STRING.h",
+        "convertBIT_STRING.h",
+        "convertIA5String.h",
+        "convertUTF8String.h",
+    ] {
+        assert!(names.contains(&name), "missing {name}");
+    }
+    let (_, ia5) = primitives
+        .iter()
+        .find(|(name, _)| name == "convertIA5String.h")
+        .unwrap();
+    assert!(!ia5.contains("#include <etsi_its_"));
+    assert!(ia5.contains("inline void toRos_IA5String(const IA5String_t& in, T& out)"));
+    assert!(ia5.contains("inline void toStruct_IA5String(const T& in, IA5String_t& out)"));
+}
+
+#[test]
+fn umbrella_includes_all_headers() {
+    let (file_name, header) = ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .generate_umbrella(&["Speeds".to_string(), "Speed".to_string()])
+        .unwrap();
+    assert_eq!(file_name, "convertTest.h");
+    assert!(header.contains(
+        "#include <etsi_its_test_conversion/convertSpeed.h>\n#include <etsi_its_test_conversion/convertSpeeds.h>"
+    ));
+}
+
+#[test]
+fn ament_package_declares_dependencies() {
+    let files = ros_backend::package::conversion_package(
+        ros_backend::package::BuildSystem::Ament,
+        "test",
+    );
+    let (_, package_xml) = files.iter().find(|(f, _)| f == "package.xml").unwrap();
+    assert!(package_xml.contains("<name>etsi_its_test_conversion</name>"));
+    assert!(package_xml.contains("<depend>etsi_its_test_coding</depend>"));
+    assert!(package_xml.contains("<depend>etsi_its_test_msgs</depend>"));
+    let (_, cmake) = files.iter().find(|(f, _)| f == "CMakeLists.txt").unwrap();
+    assert!(cmake.contains("add_library(${PROJECT_NAME} INTERFACE)"));
+}
+
+e2e_hs!(
+    boolean_ros1_only,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros1),
+    r#" Flag ::= BOOLEAN "#,
+    r#" 
+#pragma once
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Flag.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#include <etsi_its_test_msgs/Flag.h>
+namespace test_msgs = etsi_its_test_msgs;
+
+namespace etsi_its_test_conversion {
+
+struct Free_Flag {
+  void operator()(Flag_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Flag, ptr); }
+};
+using Flag_ptr = std::unique_ptr<Flag_t, Free_Flag>;
+
+void toRos_Flag(const Flag_t& in, test_msgs::Flag& out) {
+  etsi_its_primitives_conversion::toRos_BOOLEAN(in, out.value);
+}
+
+void toStruct_Flag(const test_msgs::Flag& in, Flag_t& out) {
+  memset(&out, 0, sizeof(Flag_t));
+
+  try {
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Flag, &out);
+    memset(&out, 0, sizeof(Flag_t));
+    throw;
+  }
+}
+
+Flag_ptr toStruct_Flag(const test_msgs::Flag& in) {
+  Flag_ptr out((Flag_t*) calloc(1, sizeof(Flag_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Flag(in, *out);
+  return out;
+}
+
+}"#
+);
+
+e2e_hs!(
+    main_pdu_codec,
+    r#" Test ::= BOOLEAN "#,
+    r#" 
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_coding/Test.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/Test.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/test.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif
+
+
+namespace etsi_its_test_conversion {
+
+struct Free_Test {
+  void operator()(Test_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Test, ptr); }
+};
+using Test_ptr = std::unique_ptr<Test_t, Free_Test>;
+
+void toRos_Test(const Test_t& in, test_msgs::Test& out) {
+  etsi_its_primitives_conversion::toRos_BOOLEAN(in, out.value);
+}
+
+void toStruct_Test(const test_msgs::Test& in, Test_t& out) {
+  memset(&out, 0, sizeof(Test_t));
+
+  try {
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Test, &out);
+    memset(&out, 0, sizeof(Test_t));
+    throw;
+  }
+}
+
+Test_ptr toStruct_Test(const test_msgs::Test& in) {
+  Test_ptr out((Test_t*) calloc(1, sizeof(Test_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Test(in, *out);
+  return out;
+}
+
+test_msgs::Test decodeToRos(const uint8_t* buffer, size_t size) {
+  Test_t* raw = nullptr;
+  asn_dec_rval_t ret = uper_decode_complete(nullptr, &asn_DEF_Test, (void**) &raw, buffer, size);
+  Test_ptr in(raw);
+  if (ret.code == RC_WMORE) throw std::invalid_argument("Failed to decode Test: more data expected");
+  if (ret.code != RC_OK) throw std::invalid_argument("Failed to decode Test: malformed data at byte " + std::to_string(ret.consumed));
+  test_msgs::Test out;
+  toRos_Test(*in, out);
+  return out;
+}
+
+std::vector<uint8_t> encodeFromRos(const test_msgs::Test& in) {
+  Test_ptr raw = toStruct_Test(in);
+  char error_buffer[256];
+  size_t error_length = sizeof(error_buffer);
+  if (asn_check_constraints(&asn_DEF_Test, raw.get(), error_buffer, &error_length)) {
+    throw std::invalid_argument("Invalid Test: " + std::string(error_buffer, error_length));
+  }
+  asn_encode_to_new_buffer_result_t ret = asn_encode_to_new_buffer(nullptr, ATS_UNALIGNED_BASIC_PER, &asn_DEF_Test, raw.get());
+  if (ret.result.encoded == -1) {
+    throw std::invalid_argument("Failed to encode Test: " + std::string(ret.result.failed_type ? ret.result.failed_type->name : "unknown type"));
+  }
+  std::vector<uint8_t> out((uint8_t*) ret.buffer, (uint8_t*) ret.buffer + ret.result.encoded);
+  free(ret.buffer);
+  return out;
+}
+
+}"#
+);
+
+#[test]
+fn umbrella_skipped_for_main_pdu() {
+    let umbrella = ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .generate_umbrella(&["TEST".to_string(), "Speed".to_string()]);
+    assert!(umbrella.is_none());
+}
+
+e2e_hs!(
+    boolean_type_adapter,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2)
+        .set_type_adapters(true),
+    r#" Flag ::= BOOLEAN "#,
+    r#" 
+#pragma once
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Flag.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#include <etsi_its_test_msgs/msg/flag.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+namespace etsi_its_test_conversion {
+
+struct Free_Flag {
+  void operator()(Flag_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Flag, ptr); }
+};
+using Flag_ptr = std::unique_ptr<Flag_t, Free_Flag>;
+
+void toRos_Flag(const Flag_t& in, test_msgs::Flag& out) {
+  etsi_its_primitives_conversion::toRos_BOOLEAN(in, out.value);
+}
+
+void toStruct_Flag(const test_msgs::Flag& in, Flag_t& out) {
+  memset(&out, 0, sizeof(Flag_t));
+
+  try {
+    etsi_its_primitives_conversion::toStruct_BOOLEAN(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Flag, &out);
+    memset(&out, 0, sizeof(Flag_t));
+    throw;
+  }
+}
+
+Flag_ptr toStruct_Flag(const test_msgs::Flag& in) {
+  Flag_ptr out((Flag_t*) calloc(1, sizeof(Flag_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Flag(in, *out);
+  return out;
+}
+
+}
+
+#include <rclcpp/type_adapter.hpp>
+
+template <>
+struct rclcpp::TypeAdapter<Flag_t, test_msgs::Flag> {
+  using is_specialized = std::true_type;
+  using custom_type = Flag_t;
+  using ros_message_type = test_msgs::Flag;
+
+  static void convert_to_ros_message(const custom_type& source, ros_message_type& destination) {
+    etsi_its_test_conversion::toRos_Flag(source, destination);
+  }
+
+  static void convert_to_custom(const ros_message_type& source, custom_type& destination) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Flag, &destination);
+    etsi_its_test_conversion::toStruct_Flag(source, destination);
+  }
+};"#
+);
+
+e2e_hs!(
+    integer_unit_helpers,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2)
+        .set_unit_helpers(true),
+    r#" Altitude ::= INTEGER {oneCentimeter(1)} (-100000..800001) "#,
+    r#" 
+#pragma once
+#include <memory>
+#include <stdexcept>
+#include <cmath>
+
+#include <etsi_its_test_coding/Altitude.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#include <etsi_its_test_msgs/msg/altitude.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+namespace etsi_its_test_conversion {
+
+struct Free_Altitude {
+  void operator()(Altitude_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Altitude, ptr); }
+};
+using Altitude_ptr = std::unique_ptr<Altitude_t, Free_Altitude>;
+
+void toRos_Altitude(const Altitude_t& in, test_msgs::Altitude& out) {
+  etsi_its_primitives_conversion::toRos_INTEGER(in, out.value);
+}
+
+void toStruct_Altitude(const test_msgs::Altitude& in, Altitude_t& out) {
+  memset(&out, 0, sizeof(Altitude_t));
+
+  try {
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.value, out);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Altitude, &out);
+    memset(&out, 0, sizeof(Altitude_t));
+    throw;
+  }
+}
+
+Altitude_ptr toStruct_Altitude(const test_msgs::Altitude& in) {
+  Altitude_ptr out((Altitude_t*) calloc(1, sizeof(Altitude_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Altitude(in, *out);
+  return out;
+}
+
+double getSI_Altitude(const test_msgs::Altitude& in) {
+  return in.value * test_msgs::Altitude::SCALE;
+}
+
+void setSI_Altitude(test_msgs::Altitude& out, double value) {
+  out.value = static_cast<decltype(out.value)>(std::llround(value / test_msgs::Altitude::SCALE));
+}
+
+}
+"#
+);
+
+#[test]
+fn unsupported_members_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::conversion::Conversion::default().set_main_pdu_name("test"))
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             Stamped ::= SEQUENCE { time UTCTime, flag BOOLEAN } \
+             Flag ::= BOOLEAN \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.generated.contains("//// BOOLEAN Flag"));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(
+        result.warnings[0].to_string(),
+        "NotYetInplemented generating bindings for Stamped: Inline UTCTime member time is currently unsupported!"
+    );
+}
+
+e2e_hs!(
+    choice_with_primitive_options,
+    r#" C ::= CHOICE { a INTEGER (0..7), b BOOLEAN } "#,
+    r#"
+
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/C.h>
+#include <etsi_its_test_coding/BOOLEAN.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertBOOLEAN.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#ifdef ROS1
+#include <etsi_its_test_msgs/C.h>
+namespace test_msgs = etsi_its_test_msgs;
+#else
+#include <etsi_its_test_msgs/msg/c.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+#endif
+
+
+namespace etsi_its_test_conversion {
+
+struct Free_C {
+  void operator()(C_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_C, ptr); }
+};
+using C_ptr = std::unique_ptr<C_t, Free_C>;
+
+void toRos_C(const C_t& in, test_msgs::C& out) {
+  switch (in.present) {
+  case C_PR_a:
+    etsi_its_primitives_conversion::toRos_INTEGER(in.choice.a, out.a);
+    out.choice = test_msgs::C::CHOICE_A;
+    break;
+  case C_PR_b:
+    etsi_its_primitives_conversion::toRos_BOOLEAN(in.choice.b, out.b);
+    out.choice = test_msgs::C::CHOICE_B;
+    break;
+  default: break;
+  }
+}
+
+void toStruct_C(const test_msgs::C& in, C_t& out) {
+  memset(&out, 0, sizeof(C_t));
+
+  try {
+    switch (in.choice) {
+    case test_msgs::C::CHOICE_A:
+      etsi_its_primitives_conversion::toStruct_INTEGER(in.a, out.choice.a);
+      out.present = C_PR::C_PR_a;
+      break;
+    case test_msgs::C::CHOICE_B:
+      etsi_its_primitives_conversion::toStruct_BOOLEAN(in.b, out.choice.b);
+      out.present = C_PR::C_PR_b;
+      break;
+    default: break;
+    }
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_C, &out);
+    memset(&out, 0, sizeof(C_t));
+    throw;
+  }
+}
+
+C_ptr toStruct_C(const test_msgs::C& in) {
+  C_ptr out((C_t*) calloc(1, sizeof(C_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_C(in, *out);
+  return out;
+}
+
+}"#
+);
+
+#[test]
+fn inline_constructed_members_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::conversion::Conversion::default().set_main_pdu_name("test"))
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             C ::= CHOICE { a INTEGER (0..7), inner SEQUENCE { x BOOLEAN } } \
+             S ::= SEQUENCE { n NULL, b BOOLEAN } \
+             Seq ::= SEQUENCE { flags BIT STRING (SIZE(8)), n INTEGER (0..10) } \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    let warnings: Vec<String> = result.warnings.iter().map(|w| w.to_string()).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "NotYetInplemented generating bindings for C: Inline SEQUENCE member inner is currently unsupported!",
+            "NotYetInplemented generating bindings for S: Inline NULL member n is currently unsupported!",
+            "NotYetInplemented generating bindings for Seq: Inline BIT STRING member flags is currently unsupported!",
+        ]
+    );
+}
+
+#[test]
+fn unlinked_open_types_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::conversion::Conversion::default().set_main_pdu_name("test"))
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             MY-CLASS ::= CLASS { &id INTEGER UNIQUE, &Type } \
+             Set MY-CLASS ::= { { &id 1, &Type BOOLEAN } } \
+             Msg ::= SEQUENCE { id MY-CLASS.&id ({Set}), val MY-CLASS.&Type ({Set}) } \
+             Speed ::= INTEGER (0..100) \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.generated.contains("//// INTEGER Speed"));
+    assert!(!result.generated.contains("//// SEQUENCE Msg"));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(
+        result.warnings[0].to_string(),
+        "NotYetInplemented generating bindings for Msg: Open type member val without a component relation is currently unsupported!"
+    );
+}
+
+#[test]
+fn unsupported_open_types_are_skipped() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::conversion::Conversion::default().set_main_pdu_name("test"))
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             S ::= SEQUENCE { a BOOLEAN, p EMBEDDED PDV } \
+             Speed ::= INTEGER (0..100) \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.generated.contains("//// INTEGER Speed"));
+    assert!(!result.generated.contains("//// SEQUENCE S"));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(
+        result.warnings[0].to_string(),
+        "NotYetInplemented generating bindings for S: Inline EXTERNAL member p is currently unsupported!"
+    );
+}
+
+e2e_hs!(
+    renamed_types_and_members,
+    ros_backend::conversion::Conversion::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2)
+        .set_renames(
+            ros_backend::renames::Renames::default()
+                .set_type_name("TestModule.Pos", "Position")
+                .set_member_name("Pos.type", "kind")
+        ),
+    r#" Pos ::= SEQUENCE { type INTEGER (0..1) } "#,
+    r#"#pragma once
+
+#include <memory>
+#include <stdexcept>
+
+#include <etsi_its_test_coding/Pos.h>
+#include <etsi_its_test_coding/INTEGER.h>
+#include <etsi_its_primitives_conversion/convertINTEGER.h>
+#include <etsi_its_test_msgs/msg/position.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+
+namespace etsi_its_test_conversion {
+
+struct Free_Pos {
+  void operator()(Pos_t* ptr) const { ASN_STRUCT_FREE(asn_DEF_Pos, ptr); }
+};
+using Pos_ptr = std::unique_ptr<Pos_t, Free_Pos>;
+
+void toRos_Pos(const Pos_t& in, test_msgs::Position& out) {
+  etsi_its_primitives_conversion::toRos_INTEGER(in.type, out.kind);
+}
+
+void toStruct_Pos(const test_msgs::Position& in, Pos_t& out) {
+  memset(&out, 0, sizeof(Pos_t));
+  try {
+    etsi_its_primitives_conversion::toStruct_INTEGER(in.kind, out.type);
+  } catch (...) {
+    ASN_STRUCT_FREE_CONTENTS_ONLY(asn_DEF_Pos, &out);
+    memset(&out, 0, sizeof(Pos_t));
+    throw;
+  }
+}
+
+Pos_ptr toStruct_Pos(const test_msgs::Position& in) {
+  Pos_ptr out((Pos_t*) calloc(1, sizeof(Pos_t)));
+  if (!out) throw std::bad_alloc();
+  toStruct_Pos(in, *out);
+  return out;
 }
 
 }"#
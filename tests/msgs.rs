@@ -1,5 +1,7 @@
 mod utils;
 
+use ros_backend::package::BuildSystem;
+
 e2e_msgs!(
     single_byte,
     r#" SingleByte ::= INTEGER (0..255)"#,
@@ -28,6 +30,31 @@ e2e_msgs!(
         int64 VALUE_MAX = -1 "#
 );
 
+e2e_msgs!(
+    integer_unit_from_comment,
+    r#"
+    -- @unit: 0,01 m/s
+    Speed ::= INTEGER (0..16383)"#,
+    r#" # @unit: 0,01 m/s
+        uint16 value
+        uint16 VALUE_MIN = 0
+        uint16 VALUE_MAX = 16383
+        float64 SCALE = 0.01
+        string UNIT = m/s "#
+);
+
+e2e_msgs!(
+    integer_unit_from_distinguished_value,
+    r#" Latitude ::= INTEGER {oneMicrodegreeNorth(10), unavailable(900000001)} (-900000000..900000001)"#,
+    r#" int32 value
+        int32 VALUE_MIN = -900000000
+        int32 VALUE_MAX = 900000001
+        int32 ONE_MICRODEGREE_NORTH = 10
+        int32 UNAVAILABLE = 900000001
+        float64 SCALE = 0.0000001
+        string UNIT = degree "#
+);
+
 e2e_msgs!(
     sequence,
     r#" Seq ::= SEQUENCE { aBigNumber INTEGER, anotherBigNumber INTEGER} "#,
@@ -37,6 +64,18 @@ e2e_msgs!(
 
 e2e_msgs!(boolean, r#" Maybe ::= BOOLEAN "#, r#" bool value "#);
 
+e2e_msgs!(
+    sequence_of_primitive,
+    r#" LI ::= SEQUENCE OF INTEGER (0..7) "#,
+    r#" uint8[] array "#
+);
+
+e2e_msgs!(
+    set_of_primitive,
+    r#" Flags ::= SET OF BOOLEAN "#,
+    r#" bool[] array "#
+);
+
 e2e_msgs!(
     choice,
     r#" Choose ::= CHOICE {aNumber INTEGER, aByteString OCTET STRING}"#,
@@ -48,3 +87,184 @@ e2e_msgs!(
         uint8 CHOICE_A_NUMBER = 0
         uint8 CHOICE_A_BYTE_STRING = 1"#
 );
+
+#[test]
+fn manifest_dependency_order() {
+    let messages = vec![
+        (
+            "Outer".to_string(),
+            "Speeds speeds\nExt[] exts\n".to_string(),
+        ),
+        ("Ext".to_string(), "int64 a\nSpeed e\n".to_string()),
+        (
+            "Speeds".to_string(),
+            "Speed[] array\nuint8 LENGTH_MIN = 1\n".to_string(),
+        ),
+        ("Speed".to_string(), "uint8 value\n".to_string()),
+    ];
+    let msgs = ros_backend::msgs::Msgs::default();
+    let (file_name, manifest) = msgs.generate_manifest(&messages, Some(BuildSystem::Ament));
+    assert_eq!(file_name, "msg_files.cmake");
+    assert!(manifest.contains("rosidl_generate_interfaces(${PROJECT_NAME} ${ROS_MSG_FILES})"));
+    assert!(manifest.contains(
+        "set(ROS_MSG_FILES\n  msg/Speed.msg\n  msg/Ext.msg\n  msg/Speeds.msg\n  msg/Outer.msg\n)"
+    ));
+
+    let (_, manifest) = msgs.generate_manifest(&messages, Some(BuildSystem::Catkin));
+    assert!(manifest.contains("add_message_files(DIRECTORY msg FILES ${ROS_MSG_FILES})"));
+    assert!(manifest
+        .contains("set(ROS_MSG_FILES\n  Speed.msg\n  Ext.msg\n  Speeds.msg\n  Outer.msg\n)"));
+
+    let (_, manifest) = msgs.generate_manifest(&messages, None);
+    assert!(!manifest.contains("rosidl_generate_interfaces"));
+    assert!(manifest
+        .contains("set(ROS_MSG_FILES\n  Speed.msg\n  Ext.msg\n  Speeds.msg\n  Outer.msg\n)"));
+}
+
+#[test]
+fn type_description_hashes() {
+    let messages = vec![("String".to_string(), "# comment\nstring data\n".to_string())];
+    let files = ros_backend::msgs::Msgs::default()
+        .generate_type_descriptions("std_msgs", &messages)
+        .unwrap();
+    assert_eq!(files[0].0, "String.json");
+    // hash reported by `ros2 interface` for std_msgs/msg/String
+    assert!(files[0].1.contains(
+        r#""hash_string": "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18""#
+    ));
+
+    let messages = vec![
+        (
+            "Speeds".to_string(),
+            "Speed[<=8] array\nuint8 LENGTH_MIN = 1\n".to_string(),
+        ),
+        ("Speed".to_string(), "uint8 value\n".to_string()),
+    ];
+    let files = ros_backend::msgs::Msgs::default()
+        .generate_type_descriptions("test_msgs", &messages)
+        .unwrap();
+    let speeds = &files[0].1;
+    assert!(speeds.contains(r#""type_id": 97,"#));
+    assert!(speeds.contains(r#""capacity": 8,"#));
+    assert!(speeds.contains(r#""nested_type_name": "test_msgs/msg/Speed""#));
+    assert!(!speeds.contains("LENGTH_MIN"));
+    // hashes of Speeds and of the referenced Speed
+    assert_eq!(speeds.matches("RIHS01_").count(), 2);
+}
+
+#[test]
+fn md5sums_of_nested_messages() {
+    let messages = vec![
+        (
+            "Pose".to_string(),
+            "# A pose\nPoint position\nQuaternion orientation\n".to_string(),
+        ),
+        (
+            "Point".to_string(),
+            "float64 x\nfloat64 y\nfloat64 z\n".to_string(),
+        ),
+        (
+            "Quaternion".to_string(),
+            "float64 x\nfloat64 y\nfloat64 z\nfloat64 w\n".to_string(),
+        ),
+    ];
+    let (file_name, md5sums) = ros_backend::msgs::Msgs::default()
+        .generate_md5sums("geometry_msgs", &messages)
+        .unwrap();
+    assert_eq!(file_name, "md5sums.txt");
+    // sums reported by `rosmsg md5`
+    assert_eq!(
+        md5sums,
+        "geometry_msgs/Point 4a842b65f413084dc2b10fb484ea7f17\n\
+         geometry_msgs/Pose e45d45a5a1ce597b249e23fb30fc871f\n\
+         geometry_msgs/Quaternion a779879fadf0160734f906b8c19c7004\n"
+    );
+
+    let messages = vec![("Speed".to_string(), "Unknown value\n".to_string())];
+    assert!(ros_backend::msgs::Msgs::default()
+        .generate_md5sums("test_msgs", &messages)
+        .is_err());
+}
+
+#[test]
+fn catkin_package_lists_messages() {
+    let files = ros_backend::package::msgs_package(
+        ros_backend::package::BuildSystem::Catkin,
+        "test",
+        &["Speed".to_string(), "Speeds".to_string()],
+    );
+    let (_, cmake) = files.iter().find(|(f, _)| f == "CMakeLists.txt").unwrap();
+    assert!(cmake.contains("project(etsi_its_test_msgs)"));
+    assert!(cmake.contains("add_message_files(DIRECTORY msg FILES\n  Speed.msg\n  Speeds.msg\n)"));
+}
+
+#[test]
+fn unsupported_types_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::msgs::Msgs::default())
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             Nothing ::= NULL \
+             Maybe ::= BOOLEAN \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.generated.contains("## BOOLEAN Maybe"));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(
+        result.warnings[0].to_string(),
+        "NotYetInplemented generating bindings for Nothing: NULL types are currently unsupported!"
+    );
+}
+
+#[test]
+fn unsupported_open_types_are_skipped() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::msgs::Msgs::default())
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             S ::= SEQUENCE { a BOOLEAN, p EMBEDDED PDV } \
+             Speed ::= INTEGER (0..100) \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    assert!(result.generated.contains("## INTEGER Speed"));
+    assert!(!result.generated.contains("## SEQUENCE S"));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(
+        result.warnings[0].to_string(),
+        "NotYetInplemented generating bindings for S: Inline EXTERNAL member p is currently unsupported!"
+    );
+}
+
+e2e_msgs!(
+    renamed_types_and_members,
+    ros_backend::msgs::Msgs::default().set_renames(
+        ros_backend::renames::Renames::default()
+            .set_type_name("TestModule.Speed", "Velocity")
+            .set_member_name("Pos.type", "kind")
+            .set_member_name("Choice.speed", "velocity")
+    ),
+    r#" Pos ::= SEQUENCE { type INTEGER (0..1), speed Speed OPTIONAL }
+        Choice ::= CHOICE { pos Pos, speed Speed }
+        Speed ::= INTEGER (0..100) "#,
+    r#" uint8 choice
+
+        Pos pos
+        Velocity velocity
+
+        uint8 CHOICE_POS = 0
+        uint8 CHOICE_VELOCITY = 1
+
+        ## SEQUENCE Pos
+        uint8 kind
+        bool speed_is_present
+        Velocity speed
+
+        ## INTEGER Velocity
+        uint8 value
+        uint8 VALUE_MIN = 0
+        uint8 VALUE_MAX = 100 "#
+);
@@ -0,0 +1,148 @@
+use std::path::PathBuf;
+
+use ros_backend::cli::{diff_files, manifest_name, write_files, Files};
+
+fn files(entries: &[(&str, &str)]) -> Files {
+    entries
+        .iter()
+        .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+        .collect()
+}
+
+#[test]
+fn write_files_keeps_unchanged_and_removes_stale() {
+    let out = std::env::temp_dir().join(format!("asn1-to-ros-output-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&out);
+    std::fs::create_dir_all(&out).unwrap();
+    std::fs::write(out.join("README.md"), "not generated").unwrap();
+
+    write_files(
+        &out,
+        "msgs",
+        &files(&[
+            ("msg/A.msg", "uint8 a\n"),
+            ("msg/B.msg", "uint8 b\n"),
+            ("CMakeLists.txt", ""),
+        ]),
+    )
+    .unwrap();
+    let modified = std::fs::metadata(out.join("msg/A.msg"))
+        .unwrap()
+        .modified()
+        .unwrap();
+
+    write_files(
+        &out,
+        "msgs",
+        &files(&[("msg/A.msg", "uint8 a\n"), ("CMakeLists.txt", "")]),
+    )
+    .unwrap();
+    assert_eq!(
+        std::fs::metadata(out.join("msg/A.msg"))
+            .unwrap()
+            .modified()
+            .unwrap(),
+        modified
+    );
+    assert!(!out.join("msg/B.msg").exists());
+    assert!(out.join("README.md").exists());
+    assert_eq!(
+        std::fs::read_to_string(out.join(manifest_name("msgs"))).unwrap(),
+        "CMakeLists.txt\nmsg/A.msg\n"
+    );
+
+    write_files(&out, "msgs", &files(&[("CMakeLists.txt", "")])).unwrap();
+    assert!(!out.join("msg").exists());
+
+    std::fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn diff_files_reports_changed_added_and_removed() {
+    let out = std::env::temp_dir().join(format!("asn1-to-ros-diff-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&out);
+    write_files(
+        &out,
+        "msgs",
+        &files(&[("A.msg", "uint8 a\n"), ("B.msg", "uint8 b\n")]),
+    )
+    .unwrap();
+    assert!(diff_files(
+        &out,
+        "msgs",
+        &files(&[("A.msg", "uint8 a\n"), ("B.msg", "uint8 b\n")])
+    )
+    .unwrap()
+    .is_empty());
+
+    let diffs = diff_files(
+        &out,
+        "msgs",
+        &files(&[("A.msg", "uint16 a\n"), ("C.msg", "uint8 c\n")]),
+    )
+    .unwrap();
+    assert_eq!(
+        diffs,
+        vec![
+            "--- a/.asn1-to-ros-msgs-files\n+++ b/.asn1-to-ros-msgs-files\n@@ -1,2 +1,2 @@\n A.msg\n-B.msg\n+C.msg\n",
+            "--- a/A.msg\n+++ b/A.msg\n@@ -1 +1 @@\n-uint8 a\n+uint16 a\n",
+            "--- /dev/null\n+++ b/C.msg\n@@ -0,0 +1 @@\n+uint8 c\n",
+            "--- a/B.msg\n+++ /dev/null\n@@ -1 +0,0 @@\n-uint8 b\n",
+        ]
+    );
+    assert!(!out.join("C.msg").exists());
+
+    std::fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn generators_sharing_an_output_directory_keep_each_others_files() {
+    let out = std::env::temp_dir().join(format!("asn1-to-ros-shared-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&out);
+    std::fs::create_dir_all(&out).unwrap();
+    let asn = out.join("a.asn");
+    std::fs::write(
+        &asn,
+        "A DEFINITIONS AUTOMATIC TAGS ::= BEGIN\nSpeed ::= INTEGER (0..100)\nEND\n",
+    )
+    .unwrap();
+
+    let run = |bin: &str| {
+        let status = std::process::Command::new(bin)
+            .args(["-p", "test", "-o"])
+            .args([&out, &asn])
+            .status()
+            .unwrap();
+        assert!(status.success());
+    };
+    run(env!("CARGO_BIN_EXE_asn1-to-ros-msgs"));
+    run(env!("CARGO_BIN_EXE_asn1-to-ros-conversion-headers"));
+    run(env!("CARGO_BIN_EXE_asn1-to-ros-msgs"));
+    assert!(out.join("Speed.msg").exists());
+    assert!(out.join("convertSpeed.h").exists());
+
+    run(env!("CARGO_BIN_EXE_asn1-to-ros-rust-conversion"));
+    run(env!("CARGO_BIN_EXE_asn1-to-ros-python-conversion"));
+    run(env!("CARGO_BIN_EXE_asn1-to-ros-validation-headers"));
+    for (file, generator) in [
+        ("etsi_its_test_conversion.rs", "rust-conversion"),
+        ("etsi_its_test_conversion.py", "python-conversion"),
+        ("validateSpeed.h", "validation"),
+    ] {
+        assert!(out.join(file).exists());
+        assert!(out.join(manifest_name(generator)).exists());
+    }
+    let status = std::process::Command::new(env!("CARGO_BIN_EXE_asn1-to-ros"))
+        .args(["validation", "--check", "-p", "test", "-o"])
+        .args([&out, &asn])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    write_files(&out, "conversion", &Files::new()).unwrap();
+    assert!(out.join("Speed.msg").exists());
+    assert!(!out.join("convertSpeed.h").exists());
+    assert!(out.join("validateSpeed.h").exists());
+
+    std::fs::remove_dir_all(&out).unwrap();
+}
@@ -0,0 +1,219 @@
+mod utils;
+
+e2e_module!(
+    enumerated,
+    ros_backend::python_conversion::PythonConversion::default().set_main_pdu_name("test"),
+    r#"Color ::= ENUMERATED { red, dark-green, ... }"#,
+    r#"# ASN.1 module TestModule
+
+
+class Color:
+    """ENUMERATED Color"""
+
+    _TO_ROS = {
+        "red": ros.Color.RED,
+        "dark-green": ros.Color.DARK_GREEN,
+    }
+    _TO_ASN1 = {v: k for k, v in _TO_ROS.items()}
+
+    @staticmethod
+    def to_ros(value: str) -> ros.Color:
+        if value not in Color._TO_ROS:
+            raise ValueError(f"Color: unknown enumeral {value}")
+        return ros.Color(value=Color._TO_ROS[value])
+
+    @staticmethod
+    def to_asn1(msg: ros.Color) -> str:
+        if msg.value not in Color._TO_ASN1:
+            raise ValueError(f"Color: unknown enumeral {msg.value}")
+        return Color._TO_ASN1[msg.value]"#
+);
+
+e2e_module!(
+    sequence_extensions_optional_and_default,
+    ros_backend::python_conversion::PythonConversion::default().set_main_pdu_name("test"),
+    r#"Ext ::= SEQUENCE { a INTEGER, ..., b BOOLEAN, [[ c INTEGER, d BOOLEAN OPTIONAL ]], e BOOLEAN OPTIONAL, f BOOLEAN DEFAULT TRUE }"#,
+    r#"# ASN.1 module TestModule
+
+
+class Ext:
+    """SEQUENCE Ext"""
+
+    @staticmethod
+    def to_ros(value: dict) -> ros.Ext:
+        msg = ros.Ext()
+        msg.a = value["a"]
+        if "b" in value:
+            msg.b = value["b"]
+        if any(k in value for k in ("c", "d")):
+            msg.ext_group_c = Extext_group_c.to_ros(value)
+            msg.ext_group_c_is_present = True
+        if "e" in value:
+            msg.e = value["e"]
+            msg.e_is_present = True
+        if "f" in value:
+            msg.f = value["f"]
+        return msg
+
+    @staticmethod
+    def to_asn1(msg: ros.Ext) -> dict:
+        value = {}
+        value["a"] = msg.a
+        value["b"] = msg.b
+        if msg.ext_group_c_is_present:
+            value.update(Extext_group_c.to_asn1(msg.ext_group_c))
+        if msg.e_is_present:
+            value["e"] = msg.e
+        value["f"] = msg.f
+        return value
+
+
+class Extext_group_c:
+    """SEQUENCE Ext.ext_group_c"""
+
+    @staticmethod
+    def to_ros(value: dict) -> ros.Extext_group_c:
+        msg = ros.Extext_group_c()
+        msg.c = value["c"]
+        if "d" in value:
+            msg.d = value["d"]
+            msg.d_is_present = True
+        return msg
+
+    @staticmethod
+    def to_asn1(msg: ros.Extext_group_c) -> dict:
+        value = {}
+        value["c"] = msg.c
+        if msg.d_is_present:
+            value["d"] = msg.d
+        return value"#
+);
+
+e2e_module!(
+    choice,
+    ros_backend::python_conversion::PythonConversion::default().set_main_pdu_name("test"),
+    r#"C ::= CHOICE { a INTEGER (0..7), b-B OCTET STRING }"#,
+    r#"# ASN.1 module TestModule
+
+
+class C:
+    """CHOICE C"""
+
+    @staticmethod
+    def to_ros(value: tuple) -> ros.C:
+        option, v = value
+        msg = ros.C()
+        if option == "a":
+            msg.choice = ros.C.CHOICE_A
+            msg.a = v
+        elif option == "b-B":
+            msg.choice = ros.C.CHOICE_B_B
+            msg.b_b = list(v)
+        else:
+            raise ValueError(f"C: unknown choice {option}")
+        return msg
+
+    @staticmethod
+    def to_asn1(msg: ros.C) -> tuple:
+        if msg.choice == ros.C.CHOICE_A:
+            return ("a", msg.a)
+        if msg.choice == ros.C.CHOICE_B_B:
+            return ("b-B", bytes(msg.b_b))
+        raise ValueError(f"C: unknown choice {msg.choice}")"#
+);
+
+#[test]
+fn inline_constructed_members_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(
+            ros_backend::python_conversion::PythonConversion::default().set_main_pdu_name("test"),
+        )
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             C ::= CHOICE { a INTEGER (0..7), inner SEQUENCE { x BOOLEAN } } \
+             S ::= SEQUENCE { e ENUMERATED { one, two }, b BOOLEAN } \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    let warnings: Vec<String> = result.warnings.iter().map(|w| w.to_string()).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "NotYetInplemented generating bindings for C: Inline SEQUENCE member inner is currently unsupported!",
+            "NotYetInplemented generating bindings for S: Inline ENUMERATED member e is currently unsupported!",
+        ]
+    );
+}
+
+e2e_module!(
+    bit_string,
+    ros_backend::python_conversion::PythonConversion::default().set_main_pdu_name("test"),
+    r#"Bits ::= BIT STRING"#,
+    r#"# ASN.1 module TestModule
+
+
+class Bits:
+    """BIT-STRING Bits"""
+
+    @staticmethod
+    def to_ros(value: tuple) -> ros.Bits:
+        data, number_of_bits = value
+        return ros.Bits(value=list(data), bits_unused=(8 - number_of_bits % 8) % 8)
+
+    @staticmethod
+    def to_asn1(msg: ros.Bits) -> tuple:
+        return (bytes(msg.value), len(msg.value) * 8 - msg.bits_unused)"#
+);
+
+e2e_module!(
+    renamed_types_and_members,
+    ros_backend::python_conversion::PythonConversion::default()
+        .set_main_pdu_name("test")
+        .set_renames(
+            ros_backend::renames::Renames::default()
+                .set_type_name("TestModule.Speed", "Velocity")
+                .set_member_name("Choice.speed", "velocity")
+        ),
+    r#"Choice ::= CHOICE { pos INTEGER (0..1), speed Speed }
+        Speed ::= INTEGER (0..100)"#,
+    r#"# ASN.1 module TestModule
+
+
+class Choice:
+    """CHOICE Choice"""
+
+    @staticmethod
+    def to_ros(value: tuple) -> ros.Choice:
+        option, v = value
+        msg = ros.Choice()
+        if option == "pos":
+            msg.choice = ros.Choice.CHOICE_POS
+            msg.pos = v
+        elif option == "speed":
+            msg.choice = ros.Choice.CHOICE_VELOCITY
+            msg.velocity = Velocity.to_ros(v)
+        else:
+            raise ValueError(f"Choice: unknown choice {option}")
+        return msg
+
+    @staticmethod
+    def to_asn1(msg: ros.Choice) -> tuple:
+        if msg.choice == ros.Choice.CHOICE_POS:
+            return ("pos", msg.pos)
+        if msg.choice == ros.Choice.CHOICE_VELOCITY:
+            return ("speed", Velocity.to_asn1(msg.velocity))
+        raise ValueError(f"Choice: unknown choice {msg.choice}")
+
+
+class Velocity:
+    """INTEGER Speed"""
+
+    @staticmethod
+    def to_ros(value: int) -> ros.Velocity:
+        return ros.Velocity(value=value)
+
+    @staticmethod
+    def to_asn1(msg: ros.Velocity) -> int:
+        return msg.value"#
+);
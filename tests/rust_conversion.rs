@@ -0,0 +1,374 @@
+mod utils;
+
+e2e_module!(
+    enumerated,
+    ros_backend::rust_conversion::RustConversion::default().set_main_pdu_name("test"),
+    r#"Color ::= ENUMERATED { red, dark-green, ... }"#,
+    r#"#[allow(non_camel_case_types, non_snake_case, unused)]
+pub mod test_module_ros {
+    use super::test_module as asn;
+    use r2r::etsi_its_test_msgs::msg as ros;
+
+    /// Error converting between a rasn type and its ROS message
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConversionError(pub String);
+
+    impl ConversionError {
+        fn at(path: impl core::fmt::Display, error: impl core::fmt::Display) -> Self {
+            Self(format!("{path}: {error}"))
+        }
+    }
+
+    impl core::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for ConversionError {}
+
+    // ENUMERATED Color
+
+    impl TryFrom<asn::Color> for ros::Color {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::Color) -> Result<Self, Self::Error> {
+            Ok(Self {
+                value: match value {
+                    asn::Color::red => Self::RED,
+                    asn::Color::dark_green => Self::DARK_GREEN,
+                },
+            })
+        }
+    }
+
+    impl TryFrom<ros::Color> for asn::Color {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Color) -> Result<Self, Self::Error> {
+            match value.value {
+                ros::Color::RED => Ok(Self::red),
+                ros::Color::DARK_GREEN => Ok(Self::dark_green),
+                v => Err(ConversionError::at("value", format_args!("unknown enumeral {v}"))),
+            }
+        }
+    }
+}"#
+);
+
+e2e_module!(
+    sequence_optional_default_and_keyword,
+    ros_backend::rust_conversion::RustConversion::default().set_main_pdu_name("test"),
+    r#"Speed ::= INTEGER (0..100)
+    Rec ::= SEQUENCE { speed Speed, max-speed INTEGER (0..255) OPTIONAL, type BOOLEAN DEFAULT TRUE, ..., [[ c BOOLEAN ]] }"#,
+    r#"#[allow(non_camel_case_types, non_snake_case, unused)]
+pub mod test_module_ros {
+    use super::test_module as asn;
+    use r2r::etsi_its_test_msgs::msg as ros;
+
+    /// Error converting between a rasn type and its ROS message
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConversionError(pub String);
+
+    impl ConversionError {
+        fn at(path: impl core::fmt::Display, error: impl core::fmt::Display) -> Self {
+            Self(format!("{path}: {error}"))
+        }
+    }
+
+    impl core::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for ConversionError {}
+
+    // SEQUENCE Rec
+
+    impl TryFrom<asn::Rec> for ros::Rec {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::Rec) -> Result<Self, Self::Error> {
+            Ok(Self {
+                speed: value.speed.try_into().map_err(|e| ConversionError::at("speed", e))?,
+                max_speed_is_present: value.max_speed.is_some(),
+                max_speed: match value.max_speed {
+                    Some(v) => v.try_into().map_err(|e| ConversionError::at("max_speed", e))?,
+                    None => Default::default(),
+                },
+                type_: value.r_type,
+                ext_group_c_is_present: value.ext_group_c.is_some(),
+                ext_group_c: match value.ext_group_c {
+                    Some(v) => v.try_into().map_err(|e| ConversionError::at("ext_group_c", e))?,
+                    None => Default::default(),
+                },
+            })
+        }
+    }
+
+    impl TryFrom<ros::Rec> for asn::Rec {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Rec) -> Result<Self, Self::Error> {
+            Ok(Self {
+                speed: value.speed.try_into().map_err(|e| ConversionError::at("speed", e))?,
+                max_speed: if value.max_speed_is_present { Some(value.max_speed.try_into().map_err(|e| ConversionError::at("max_speed", e))?) } else { None },
+                r_type: value.type_,
+                ext_group_c: if value.ext_group_c_is_present { Some(value.ext_group_c.try_into().map_err(|e| ConversionError::at("ext_group_c", e))?) } else { None },
+            })
+        }
+    }
+
+    // SEQUENCE RecExtGroupC
+
+    impl TryFrom<asn::RecExtGroupC> for ros::Recext_group_c {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::RecExtGroupC) -> Result<Self, Self::Error> {
+            Ok(Self {
+                c: value.c,
+            })
+        }
+    }
+
+    impl TryFrom<ros::Recext_group_c> for asn::RecExtGroupC {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Recext_group_c) -> Result<Self, Self::Error> {
+            Ok(Self {
+                c: value.c,
+            })
+        }
+    }
+
+    // INTEGER Speed
+
+    impl TryFrom<asn::Speed> for ros::Speed {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::Speed) -> Result<Self, Self::Error> {
+            Ok(Self { value: value.0.try_into().map_err(|e| ConversionError::at("value", e))? })
+        }
+    }
+
+    impl TryFrom<ros::Speed> for asn::Speed {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Speed) -> Result<Self, Self::Error> {
+            Ok(Self(value.value.try_into().map_err(|e| ConversionError::at("value", e))?))
+        }
+    }
+}"#
+);
+
+e2e_module!(
+    choice,
+    ros_backend::rust_conversion::RustConversion::default().set_main_pdu_name("test"),
+    r#"C ::= CHOICE { a INTEGER (0..7), b-B OCTET STRING }"#,
+    r#"#[allow(non_camel_case_types, non_snake_case, unused)]
+pub mod test_module_ros {
+    use super::test_module as asn;
+    use r2r::etsi_its_test_msgs::msg as ros;
+
+    /// Error converting between a rasn type and its ROS message
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConversionError(pub String);
+
+    impl ConversionError {
+        fn at(path: impl core::fmt::Display, error: impl core::fmt::Display) -> Self {
+            Self(format!("{path}: {error}"))
+        }
+    }
+
+    impl core::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for ConversionError {}
+
+    // CHOICE C
+
+    impl TryFrom<asn::C> for ros::C {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::C) -> Result<Self, Self::Error> {
+            Ok(match value {
+                asn::C::a(v) => Self {
+                    choice: Self::CHOICE_A,
+                    a: v.try_into().map_err(|e| ConversionError::at("a", e))?,
+                    ..Default::default()
+                },
+                asn::C::b_B(v) => Self {
+                    choice: Self::CHOICE_B_B,
+                    b_b: v.to_vec(),
+                    ..Default::default()
+                },
+            })
+        }
+    }
+
+    impl TryFrom<ros::C> for asn::C {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::C) -> Result<Self, Self::Error> {
+            match value.choice {
+                ros::C::CHOICE_A => Ok(Self::a(value.a.try_into().map_err(|e| ConversionError::at("a", e))?)),
+                ros::C::CHOICE_B_B => Ok(Self::b_B(value.b_b.into())),
+                v => Err(ConversionError::at("choice", format_args!("unknown choice {v}"))),
+            }
+        }
+    }
+}"#
+);
+
+#[test]
+fn inline_constructed_members_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(
+            ros_backend::rust_conversion::RustConversion::default().set_main_pdu_name("test"),
+        )
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             C ::= CHOICE { a INTEGER (0..7), inner SEQUENCE { x BOOLEAN } } \
+             S ::= SEQUENCE { e ENUMERATED { one, two }, b BOOLEAN } \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    let warnings: Vec<String> = result.warnings.iter().map(|w| w.to_string()).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "NotYetInplemented generating bindings for C: Inline SEQUENCE member inner is currently unsupported!",
+            "NotYetInplemented generating bindings for S: Inline ENUMERATED member e is currently unsupported!",
+        ]
+    );
+}
+
+e2e_module!(
+    sequence_of_anonymous_elements,
+    ros_backend::rust_conversion::RustConversion::default().set_main_pdu_name("test"),
+    r#"Ints ::= SEQUENCE OF INTEGER (0..255)"#,
+    r#"#[allow(non_camel_case_types, non_snake_case, unused)]
+pub mod test_module_ros {
+    use super::test_module as asn;
+    use r2r::etsi_its_test_msgs::msg as ros;
+
+    /// Error converting between a rasn type and its ROS message
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConversionError(pub String);
+
+    impl ConversionError {
+        fn at(path: impl core::fmt::Display, error: impl core::fmt::Display) -> Self {
+            Self(format!("{path}: {error}"))
+        }
+    }
+
+    impl core::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for ConversionError {}
+
+    // SEQUENCE-OF Ints
+
+    impl TryFrom<asn::Ints> for ros::Ints {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::Ints) -> Result<Self, Self::Error> {
+            Ok(Self { array: value.0.into_iter().enumerate().map(|(i, v)| -> Result<_, ConversionError> { Ok(v.0.try_into().map_err(|e| ConversionError::at(format_args!("array[{i}]"), e))?) }).collect::<Result<_, _>>()? })
+        }
+    }
+
+    impl TryFrom<ros::Ints> for asn::Ints {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Ints) -> Result<Self, Self::Error> {
+            Ok(Self(value.array.into_iter().enumerate().map(|(i, v)| -> Result<_, ConversionError> { Ok(asn::AnonymousInts(v.try_into().map_err(|e| ConversionError::at(format_args!("array[{i}]"), e))?)) }).collect::<Result<_, _>>()?))
+        }
+    }
+}"#
+);
+
+e2e_module!(
+    renamed_types_and_members,
+    ros_backend::rust_conversion::RustConversion::default()
+        .set_main_pdu_name("test")
+        .set_renames(
+            ros_backend::renames::Renames::default()
+                .set_type_name("TestModule.Speed", "Velocity")
+                .set_member_name("Pos.type", "kind")
+        ),
+    r#"Pos ::= SEQUENCE { type INTEGER (0..1), speed Speed }
+        Speed ::= INTEGER (0..100)"#,
+    r#"#[allow(non_camel_case_types, non_snake_case, unused)]
+pub mod test_module_ros {
+    use super::test_module as asn;
+    use r2r::etsi_its_test_msgs::msg as ros;
+
+    /// Error converting between a rasn type and its ROS message
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConversionError(pub String);
+
+    impl ConversionError {
+        fn at(path: impl core::fmt::Display, error: impl core::fmt::Display) -> Self {
+            Self(format!("{path}: {error}"))
+        }
+    }
+
+    impl core::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for ConversionError {}
+
+    // SEQUENCE Pos
+
+    impl TryFrom<asn::Pos> for ros::Pos {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::Pos) -> Result<Self, Self::Error> {
+            Ok(Self {
+                kind: value.r_type.try_into().map_err(|e| ConversionError::at("kind", e))?,
+                speed: value.speed.try_into().map_err(|e| ConversionError::at("speed", e))?,
+            })
+        }
+    }
+
+    impl TryFrom<ros::Pos> for asn::Pos {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Pos) -> Result<Self, Self::Error> {
+            Ok(Self {
+                r_type: value.kind.try_into().map_err(|e| ConversionError::at("kind", e))?,
+                speed: value.speed.try_into().map_err(|e| ConversionError::at("speed", e))?,
+            })
+        }
+    }
+
+    // INTEGER Speed
+
+    impl TryFrom<asn::Speed> for ros::Velocity {
+        type Error = ConversionError;
+
+        fn try_from(value: asn::Speed) -> Result<Self, Self::Error> {
+            Ok(Self { value: value.0.try_into().map_err(|e| ConversionError::at("value", e))? })
+        }
+    }
+
+    impl TryFrom<ros::Velocity> for asn::Speed {
+        type Error = ConversionError;
+
+        fn try_from(value: ros::Velocity) -> Result<Self, Self::Error> {
+            Ok(Self(value.value.try_into().map_err(|e| ConversionError::at("value", e))?))
+        }
+    }
+}"#
+);
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use ros_backend::cli::{Source, SourceFilter};
+
+#[test]
+fn directories_are_walked_in_file_name_order() {
+    let dir = std::env::temp_dir().join(format!("asn1-to-ros-sources-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    for file in ["b/z.asn", "b/a.asn1", "a.asn", "c/test/t.asn", "notes.txt"] {
+        let path = dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "").unwrap();
+    }
+
+    let filter = SourceFilter {
+        include: vec![],
+        exclude: vec!["*/test/*".into()],
+    };
+    let sources = filter.sources(&[dir.join("b/z.asn"), dir.clone()]).unwrap();
+    assert_eq!(
+        sources,
+        ["b/z.asn", "a.asn", "b/a.asn1"]
+            .iter()
+            .map(|file| Source::Path(dir.join(file)))
+            .collect::<Vec<_>>()
+    );
+
+    let filter = SourceFilter {
+        include: vec!["b/*".into()],
+        exclude: vec![],
+    };
+    let sources = filter
+        .sources(&[dir.clone(), PathBuf::from("x.asn")])
+        .unwrap();
+    assert_eq!(
+        sources,
+        vec![
+            Source::Path(dir.join("b/a.asn1")),
+            Source::Path(dir.join("b/z.asn")),
+            Source::Path(PathBuf::from("x.asn")),
+        ]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
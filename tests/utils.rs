@@ -1,11 +1,14 @@
 #[macro_export]
 macro_rules! e2e_msgs {
     ($suite:ident, $asn1:literal, $expected:literal) => {
+        e2e_msgs!($suite, ros_backend::msgs::Msgs::default(), $asn1, $expected);
+    };
+    ($suite:ident, $backend:expr, $asn1:literal, $expected:literal) => {
         #[test]
         fn $suite() {
             assert_eq!(
                 rasn_compiler::Compiler::new()
-                    .with_backend(ros_backend::msgs::Msgs)
+                    .with_backend($backend)
                     .add_asn_literal(&format!(
                         "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END",
                         $asn1
@@ -31,13 +34,19 @@ macro_rules! e2e_msgs {
 #[macro_export]
 macro_rules! e2e_hs {
     ($suite:ident, $asn1:literal, $expected:literal) => {
+        e2e_hs!(
+            $suite,
+            ros_backend::conversion::Conversion::default().set_main_pdu_name("test"),
+            $asn1,
+            $expected
+        );
+    };
+    ($suite:ident, $backend:expr, $asn1:literal, $expected:literal) => {
         #[test]
         fn $suite() {
             assert_eq!(
                 rasn_compiler::Compiler::new()
-                    .with_backend(
-                        ros_backend::conversion::Conversion::default().set_main_pdu_name("test")
-                    )
+                    .with_backend($backend)
                     .add_asn_literal(&format!(
                         "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END",
                         $asn1
@@ -59,3 +68,25 @@ macro_rules! e2e_hs {
         }
     };
 }
+
+#[macro_export]
+macro_rules! e2e_module {
+    ($suite:ident, $backend:expr, $asn1:literal, $expected:literal) => {
+        #[test]
+        fn $suite() {
+            assert_eq!(
+                rasn_compiler::Compiler::new()
+                    .with_backend($backend)
+                    .add_asn_literal(&format!(
+                        "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN {} END",
+                        $asn1
+                    ))
+                    .compile_to_string()
+                    .unwrap()
+                    .generated
+                    .trim(),
+                $expected,
+            )
+        }
+    };
+}
@@ -0,0 +1,235 @@
+mod utils;
+
+e2e_hs!(
+    integer_bounds,
+    ros_backend::validation::Validation::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2),
+    r#"Lat ::= INTEGER (-900000000..900000001)"#,
+    r#"#pragma once
+
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_msgs/msg/lat.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+
+namespace etsi_its_test_validation {
+
+inline bool isValid(const test_msgs::Lat& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;
+  auto report = [&](const std::string& error) {
+    valid = false;
+    if (errors) errors->push_back(path + error);
+  };
+  if (in.value < test_msgs::Lat::VALUE_MIN) report("value: " + std::to_string(in.value) + " is below VALUE_MIN (-900000000)");
+  if (in.value > test_msgs::Lat::VALUE_MAX) report("value: " + std::to_string(in.value) + " is above VALUE_MAX (900000001)");
+  return valid;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_of_recurses_into_elements,
+    ros_backend::validation::Validation::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2),
+    r#"Speeds ::= SEQUENCE (SIZE(1..8)) OF Speed"#,
+    r#"#pragma once
+
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_validation/validateSpeed.h>
+#include <etsi_its_test_msgs/msg/speeds.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+
+namespace etsi_its_test_validation {
+
+inline bool isValid(const test_msgs::Speeds& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;
+  auto report = [&](const std::string& error) {
+    valid = false;
+    if (errors) errors->push_back(path + error);
+  };
+  if (in.array.size() < test_msgs::Speeds::LENGTH_MIN) report("array: " + std::to_string(in.array.size()) + " is below LENGTH_MIN (1)");
+  if (in.array.size() > test_msgs::Speeds::LENGTH_MAX) report("array: " + std::to_string(in.array.size()) + " is above LENGTH_MAX (8)");
+  for (size_t i = 0; i < in.array.size(); i++) {
+    valid = isValid(in.array[i], errors, path + "array[" + std::to_string(i) + "].") && valid;
+  }
+  return valid;
+}
+
+}"#
+);
+
+e2e_hs!(
+    sequence_with_optional_member,
+    ros_backend::validation::Validation::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2),
+    r#"Rec ::= SEQUENCE { speed Speed OPTIONAL, flag BOOLEAN, heading Heading }"#,
+    r#"#pragma once
+
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_validation/validateHeading.h>
+#include <etsi_its_test_validation/validateSpeed.h>
+#include <etsi_its_test_msgs/msg/rec.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+
+namespace etsi_its_test_validation {
+
+inline bool isValid(const test_msgs::Rec& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;
+  if (in.speed_is_present) valid = isValid(in.speed, errors, path + "speed.") && valid;
+  valid = isValid(in.heading, errors, path + "heading.") && valid;
+  return valid;
+}
+
+}"#
+);
+
+e2e_hs!(
+    inline_primitive_member_bounds,
+    ros_backend::validation::Validation::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2),
+    r#"Rec ::= SEQUENCE { n INTEGER (0..10), m INTEGER (-5..5) OPTIONAL, id OCTET STRING (SIZE(4)) }"#,
+    r#"#pragma once
+
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_msgs/msg/rec.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+
+namespace etsi_its_test_validation {
+
+inline bool isValid(const test_msgs::Rec& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;
+  auto report = [&](const std::string& error) {
+    valid = false;
+    if (errors) errors->push_back(path + error);
+  };
+  if (in.n > 10) report("n: " + std::to_string(in.n) + " is above 10");
+  if (in.m_is_present) {
+    if (in.m < -5) report("m: " + std::to_string(in.m) + " is below -5");
+    if (in.m > 5) report("m: " + std::to_string(in.m) + " is above 5");
+  }
+  if (in.id.size() != 4) report("id: " + std::to_string(in.id.size()) + " is not equal to 4");
+  return valid;
+}
+
+}"#
+);
+
+#[test]
+fn unsupported_inline_members_are_reported() {
+    let result = rasn_compiler::Compiler::new()
+        .with_backend(ros_backend::validation::Validation::default().set_main_pdu_name("test"))
+        .add_asn_literal(
+            "TestModule DEFINITIONS AUTOMATIC TAGS::= BEGIN \
+             C ::= CHOICE { a INTEGER (0..7), inner SEQUENCE { x BOOLEAN } } \
+             S ::= SEQUENCE { e ENUMERATED { one, two }, b BOOLEAN } \
+             Seq ::= SEQUENCE { flags BIT STRING (SIZE(8)), n INTEGER (0..10) } \
+             END",
+        )
+        .compile_to_string()
+        .unwrap();
+    let warnings: Vec<String> = result.warnings.iter().map(|w| w.to_string()).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "NotYetInplemented generating bindings for C: Inline SEQUENCE member inner is currently unsupported!",
+            "NotYetInplemented generating bindings for S: Inline ENUMERATED member e is currently unsupported!",
+            "NotYetInplemented generating bindings for Seq: Inline BIT STRING member flags is currently unsupported!",
+        ]
+    );
+}
+
+e2e_hs!(
+    enumerated_ros1,
+    ros_backend::validation::Validation::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros1),
+    r#"Color ::= ENUMERATED { red, dark-green }"#,
+    r#"#pragma once
+
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_msgs/Color.h>
+namespace test_msgs = etsi_its_test_msgs;
+
+
+namespace etsi_its_test_validation {
+
+inline bool isValid(const test_msgs::Color& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;
+  auto report = [&](const std::string& error) {
+    valid = false;
+    if (errors) errors->push_back(path + error);
+  };
+  switch (in.value) {
+    case test_msgs::Color::RED:
+      break;
+    case test_msgs::Color::DARK_GREEN:
+      break;
+    default:
+      report("value: " + std::to_string(in.value) + " is not a known constant");
+  }
+  return valid;
+}
+
+}"#
+);
+
+e2e_hs!(
+    renamed_types_and_members,
+    ros_backend::validation::Validation::default()
+        .set_main_pdu_name("test")
+        .set_ros_target(ros_backend::conversion::RosTarget::Ros2)
+        .set_renames(
+            ros_backend::renames::Renames::default()
+                .set_type_name("TestModule.C", "Choice")
+                .set_member_name("C.a", "alpha")
+        ),
+    r#"C ::= CHOICE { a INTEGER (0..7), b BOOLEAN }"#,
+    r#"#pragma once
+
+#include <string>
+#include <vector>
+
+#include <etsi_its_test_msgs/msg/choice.hpp>
+namespace test_msgs = etsi_its_test_msgs::msg;
+
+
+namespace etsi_its_test_validation {
+
+inline bool isValid(const test_msgs::Choice& in, std::vector<std::string>* errors = nullptr, const std::string& path = "") {
+  bool valid = true;
+  auto report = [&](const std::string& error) {
+    valid = false;
+    if (errors) errors->push_back(path + error);
+  };
+  switch (in.choice) {
+    case test_msgs::Choice::CHOICE_ALPHA:
+      if (in.alpha > 7) report("alpha: " + std::to_string(in.alpha) + " is above 7");
+      break;
+    case test_msgs::Choice::CHOICE_B:
+      break;
+    default:
+      report("choice: " + std::to_string(in.choice) + " is not a known constant");
+  }
+  return valid;
+}
+
+}"#
+);